@@ -0,0 +1,63 @@
+//! Times how long a busy 16 player night takes to resolve.
+//! Every player acts, so the visit ledger is queried by trackers, lookouts, transporters and attackers.
+//! Run with `cargo bench --bench night`
+#[path = "../tests/kit/mod.rs"]
+mod kit;
+
+use std::time::{Duration, Instant};
+
+use mafia_server::game::prelude::*;
+
+const NIGHTS: u32 = 200;
+
+fn main() {
+    let mut total = Duration::ZERO;
+    let mut slowest = Duration::ZERO;
+    for _ in 0..NIGHTS {
+        let night = busy_night();
+        total = total.saturating_add(night);
+        slowest = slowest.max(night);
+    }
+    println!("busy 16 player night: {:?} average, {:?} slowest, over {NIGHTS} nights", total / NIGHTS, slowest);
+}
+
+fn busy_night() -> Duration {
+    kit::scenario!(game in Night 2 where
+        mafioso: Mafioso,
+        consort: Consort,
+        framer: Framer,
+        witch: Witch,
+        serial_killer: SerialKiller,
+        transporter: Transporter,
+        lookout1: Lookout,
+        lookout2: Lookout,
+        tracker1: Tracker,
+        tracker2: Tracker,
+        detective: Detective,
+        doctor: Doctor,
+        bodyguard: Bodyguard,
+        escort: Escort,
+        vigilante: Vigilante,
+        villager: Villager
+    );
+
+    mafioso.send_ability_input_player_list_typical(villager);
+    consort.send_ability_input_player_list_typical(detective);
+    framer.send_ability_input_player_list_typical(doctor);
+    witch.send_ability_input_two_player_typical(vigilante, mafioso);
+    serial_killer.send_ability_input_player_list_typical(lookout1);
+    transporter.send_ability_input_two_player_typical(villager, lookout1);
+    lookout1.send_ability_input_player_list_typical(villager);
+    lookout2.send_ability_input_player_list_typical(lookout1);
+    tracker1.send_ability_input_player_list_typical(mafioso);
+    tracker2.send_ability_input_player_list_typical(serial_killer);
+    detective.send_ability_input_player_list_typical(framer);
+    doctor.send_ability_input_player_list_typical(villager);
+    bodyguard.send_ability_input_player_list_typical(lookout1);
+    escort.send_ability_input_player_list_typical(serial_killer);
+    vigilante.send_ability_input_player_list_typical(consort);
+
+    let start = Instant::now();
+    game.next_phase();
+    start.elapsed()
+}
@@ -0,0 +1,70 @@
+use std::{sync::{Arc, Mutex}, time::{Duration, Instant}};
+
+use crate::websocket_connections::ForceLock;
+
+/// Where the server gets the time from.
+/// Nothing in a room asks the system for the time, the listener asks its clock and passes it down,
+/// so a different clock can make time pass only when a test says so.
+pub trait Clock: Send {
+    fn now(&self) -> Instant;
+}
+
+/// Real time
+pub struct SystemClock;
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// How much faster than the clock time passes in rooms. A speed of `2.0` makes every phase take half as long.
+/// Only rooms are sped up, pings and the rate limiter still go by the clock.
+#[derive(Clone, Copy)]
+pub struct ClockSpeed(f64);
+impl ClockSpeed {
+    pub const ENVIRONMENT_VARIABLE: &str = "CLOCK_SPEED";
+    pub const REAL_TIME: Self = Self(1.0);
+
+    /// None unless `speed` is a positive number
+    pub fn new(speed: f64) -> Option<Self> {
+        (speed.is_finite() && speed > 0.0).then_some(Self(speed))
+    }
+    /// None unless `CLOCK_SPEED` is set to a positive number
+    pub fn from_env() -> Option<Self> {
+        std::env::var(Self::ENVIRONMENT_VARIABLE).ok()
+            .and_then(|speed| speed.parse::<f64>().ok())
+            .and_then(Self::new)
+    }
+    /// How much time passes in rooms while `time` passes on the clock
+    pub fn scale(self, time: Duration) -> Duration {
+        Duration::try_from_secs_f64(time.as_secs_f64() * self.0).unwrap_or(Duration::MAX)
+    }
+}
+impl Default for ClockSpeed {
+    fn default() -> Self {
+        Self::REAL_TIME
+    }
+}
+
+/// Only moves when [`ManualClock::advance`] is called.
+/// Clones share the same time, so one can be given to the listener and another kept to move it.
+#[derive(Clone)]
+pub struct ManualClock {
+    start: Instant,
+    elapsed: Arc<Mutex<Duration>>,
+}
+impl ManualClock {
+    pub fn new() -> Self {
+        Self { start: Instant::now(), elapsed: Arc::new(Mutex::new(Duration::ZERO)) }
+    }
+    pub fn advance(&self, time: Duration) {
+        let mut elapsed = self.elapsed.force_lock();
+        *elapsed = elapsed.saturating_add(time);
+    }
+}
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        let elapsed = *self.elapsed.force_lock();
+        self.start.checked_add(elapsed).unwrap_or(self.start)
+    }
+}
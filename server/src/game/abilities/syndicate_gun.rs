@@ -127,6 +127,7 @@ impl AbilityTrait for SyndicateGun {
                 for (attacker, target) in Visits::into_iter(midnight_variables)
                     .with_tag(VisitTag::SyndicateGun)
                     .map(|visit| (visit.visitor, visit.target))
+                    .collect::<Vec<_>>()
                 {
                     NightAttack::new()
                         .attackers([attacker])
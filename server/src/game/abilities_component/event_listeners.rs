@@ -3,62 +3,83 @@ use crate::game::{
         AsInvokable as _, Invokable as _, before_phase_end::BeforePhaseEnd, on_ability_creation::{OnAbilityCreation, OnAbilityCreationFold, OnAbilityCreationPriority}, on_ability_deletion::{OnAbilityDeletion, OnAbilityDeletionPriority}, on_ability_edit::OnAbilityEdit, on_add_insider::OnAddInsider, on_any_death::OnAnyDeath, on_conceal_role::OnConcealRole, on_controller_selection_changed::OnControllerSelectionChanged, on_grave_added::OnGraveAdded, on_midnight::{OnMidnight, OnMidnightFold, OnMidnightPriority}, on_phase_start::OnPhaseStart, on_player_possessed::OnPlayerPossessed, on_player_roleblocked::OnPlayerRoleblocked, on_remove_insider::OnRemoveInsider, on_role_switch::OnRoleSwitch, on_validated_ability_input_received::OnValidatedControllerInputReceived, on_visit_wardblocked::OnVisitWardblocked, on_whisper::{OnWhisper, WhisperFold, WhisperPriority},
     }
 };
+use crate::{event_listener, game::event::registry::{EventRegistry, ListenerOrder}};
 
 impl Abilities{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(Abilities::before_phase_end));
+        registry.subscribe(event_listener!(Abilities::on_ability_creation));
+        registry.subscribe(event_listener!(Abilities::on_ability_deletion));
+        registry.subscribe(event_listener!(Abilities::on_add_insider));
+        registry.subscribe(event_listener!(Abilities::on_any_death).order(ListenerOrder::Late));
+        registry.subscribe(event_listener!(Abilities::on_conceal_role));
+        registry.subscribe(event_listener!(Abilities::on_controller_selection_changed));
+        registry.subscribe(event_listener!(Abilities::on_grave_added));
+        registry.subscribe(event_listener!(Abilities::on_midnight).order(ListenerOrder::Late));
+        registry.subscribe(event_listener!(Abilities::on_phase_start));
+        registry.subscribe(event_listener!(Abilities::on_player_possessed));
+        registry.subscribe(event_listener!(Abilities::on_player_roleblocked));
+        registry.subscribe(event_listener!(Abilities::on_remove_insider));
+        registry.subscribe(event_listener!(Abilities::on_role_switch).order(ListenerOrder::Late));
+        registry.subscribe(event_listener!(Abilities::on_validated_ability_input_received));
+        registry.subscribe(event_listener!(Abilities::on_visit_wardblocked));
+        registry.subscribe(event_listener!(Abilities::on_whisper).order(ListenerOrder::Late));
+    }
+
+    /// Calls `f` with every ability, skipping the ones an earlier ability deleted during the same event,
+    /// like the other roles of a Maverick or Juggernaut that was converted
+    fn for_each_ability(game: &mut Game, mut f: impl FnMut(&mut Game, AbilityID)) {
+        let ids: Vec<AbilityID> = game.abilities.abilities.keys().copied().collect();
+        for id in ids {
+            if game.abilities.abilities.contains_key(&id) {
+                f(game, id);
+            }
+        }
+    }
+
     pub fn on_midnight(game: &mut Game, _event: &OnMidnight, midnight_variables: &mut OnMidnightFold, priority: OnMidnightPriority){
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_midnight(game, _event, midnight_variables, priority)
+        #[cfg_attr(not(any(test, feature = "test-kit")), expect(unused_mut, reason = "Only shuffled in tests"))]
+        let mut ids: Vec<AbilityID> = game.abilities.abilities.keys().copied().collect();
+        #[cfg(any(test, feature = "test-kit"))]
+        game.event_registry.shuffle::<OnMidnight, _>(&mut ids);
+        for id in ids {
+            if !game.abilities.abilities.contains_key(&id) {continue}
+            let snapshot = midnight_variables.trace_snapshot();
+            id.on_midnight(game, _event, midnight_variables, priority);
+            if let Some(snapshot) = snapshot {
+                midnight_variables.trace_ability(id, snapshot);
+            }
         }
     }
     pub fn on_whisper(game: &mut Game, event: &OnWhisper, fold: &mut WhisperFold, priority: WhisperPriority) {
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_whisper(game, event, fold, priority)
-        }
+        Self::for_each_ability(game, |game, id| id.on_whisper(game, event, fold, priority));
     }
     pub fn on_grave_added(game: &mut Game, event: &OnGraveAdded, fold: &mut (), priority: ()){
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_grave_added(game, event, fold, priority)
-        }
+        Self::for_each_ability(game, |game, id| id.on_grave_added(game, event, fold, priority));
     }
     pub fn on_validated_ability_input_received(game: &mut Game, event: &OnValidatedControllerInputReceived, fold: &mut (), priority: ()) {
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_validated_ability_input_received(game, event, fold, priority)
-        }
+        Self::for_each_ability(game, |game, id| id.on_validated_ability_input_received(game, event, fold, priority));
     }
     pub fn on_controller_selection_changed(game: &mut Game, event: &OnControllerSelectionChanged, fold: &mut (), priority: ()) {
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_controller_selection_changed(game, event, fold, priority)
-        }
+        Self::for_each_ability(game, |game, id| id.on_controller_selection_changed(game, event, fold, priority));
     }
     pub fn on_phase_start(game: &mut Game, event: &OnPhaseStart, _fold: &mut (), _priority: ()){
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_phase_start(game, event, _fold, _priority);
-        }
+        Self::for_each_ability(game, |game, id| id.on_phase_start(game, event, _fold, _priority));
     }
     pub fn before_phase_end(game: &mut Game, event: &BeforePhaseEnd, _fold: &mut (), _priority: ()){
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.before_phase_end(game, event, _fold, _priority);
-        }
+        Self::for_each_ability(game, |game, id| id.before_phase_end(game, event, _fold, _priority));
     }
     pub fn on_conceal_role(game: &mut Game, event: &OnConcealRole, fold: &mut (), priority: ()) {
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_conceal_role(game, event, fold, priority);
-        }
+        Self::for_each_ability(game, |game, id| id.on_conceal_role(game, event, fold, priority));
     }
     pub fn on_add_insider(game: &mut Game, event: &OnAddInsider, fold: &mut (), priority: ()) {
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_add_insider(game, event, fold, priority);
-        }
+        Self::for_each_ability(game, |game, id| id.on_add_insider(game, event, fold, priority));
     }
     pub fn on_remove_insider(game: &mut Game, event: &OnRemoveInsider, fold: &mut (), priority: ()) {
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_remove_insider(game, event, fold, priority);
-        }
+        Self::for_each_ability(game, |game, id| id.on_remove_insider(game, event, fold, priority));
     }
     pub fn on_any_death(game: &mut Game, event: &OnAnyDeath, fold: &mut (), priority: ()) {
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_any_death(game, event, fold, priority);
-        }
+        Self::for_each_ability(game, |game, id| id.on_any_death(game, event, fold, priority));
     }
     pub fn on_ability_creation(game: &mut Game, event: &OnAbilityCreation, fold: &mut OnAbilityCreationFold, priority: OnAbilityCreationPriority) {
         match priority {
@@ -72,14 +93,10 @@ impl Abilities{
             _ => {}
         }
 
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_ability_creation(game, event, fold, priority);
-        }
+        Self::for_each_ability(game, |game, id| id.on_ability_creation(game, event, fold, priority));
     }
     pub fn on_ability_deletion(game: &mut Game, event: &OnAbilityDeletion, fold: &mut (), priority: OnAbilityDeletionPriority) {
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_ability_deletion(game, event, fold, priority);
-        }
+        Self::for_each_ability(game, |game, id| id.on_ability_deletion(game, event, fold, priority));
 
         if priority == OnAbilityDeletionPriority::DeleteAbility {
             game.abilities.abilities.remove(&event.id);
@@ -87,27 +104,19 @@ impl Abilities{
         }   
     }
     pub fn on_role_switch(game: &mut Game, event: &OnRoleSwitch, fold: &mut (), priority: ()){
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_role_switch(game, event, fold, priority);
-        }
+        Self::for_each_ability(game, |game, id| id.on_role_switch(game, event, fold, priority));
     }
 
     pub fn on_player_possessed(game: &mut Game, event: &OnPlayerPossessed, fold: &mut OnMidnightFold, priority: ()){
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_player_possessed(game, event, fold, priority);
-        }
+        Self::for_each_ability(game, |game, id| id.on_player_possessed(game, event, fold, priority));
     }
 
     
     pub fn on_player_roleblocked(game: &mut Game, event: &OnPlayerRoleblocked, fold: &mut OnMidnightFold, priority: ()) {
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_player_roleblocked(game, event, fold, priority)
-        }
+        Self::for_each_ability(game, |game, id| id.on_player_roleblocked(game, event, fold, priority));
     }
     pub fn on_visit_wardblocked(game: &mut Game, event: &OnVisitWardblocked, fold: &mut OnMidnightFold, priority: ()) {
-        for (id, _ability) in game.abilities.abilities.clone() {
-            id.on_visit_wardblocked(game, event, fold, priority)
-        }
+        Self::for_each_ability(game, |game, id| id.on_visit_wardblocked(game, event, fold, priority));
     }
 
 
@@ -1,5 +1,5 @@
 use serde::Serialize;
-use crate::{game::{components::synopsis::Synopsis, prelude::*, role::{auditor::AuditorResult, engineer::TrapState, kira::KiraResult, krampus::KrampusAbility, santa_claus::SantaListKind}, role_outline_reference::OutlineIndex, verdict::Verdict}, vec_set::VecSet};
+use crate::{game::{components::{moderation::ModerationAction, synopsis::Synopsis}, prelude::*, role::{auditor::AuditorResult, engineer::TrapState, kira::KiraResult, krampus::KrampusAbility, santa_claus::SantaListKind}, role_outline_reference::OutlineIndex, verdict::Verdict}, vec_set::VecSet};
 
 #[derive(Clone, Debug, Serialize, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(rename_all = "camelCase")]
@@ -52,6 +52,10 @@ pub enum ChatMessageVariant {
     GameOver { synopsis: Synopsis },
     #[serde(rename_all = "camelCase")]
     PlayerQuit{player_index: PlayerReference, game_over: bool},
+    #[serde(rename_all = "camelCase")]
+    InactivityWarning{phases_left: Option<u8>},
+    #[serde(rename_all = "camelCase")]
+    PlayerRemovedForInactivity{player: PlayerReference, replaced: bool},
 
 
     
@@ -73,6 +77,11 @@ pub enum ChatMessageVariant {
         votee: Option<PlayerReference> 
     },
     #[serde(rename_all = "camelCase")]
+    VotedForPlayers {
+        voter: PlayerReference,
+        votees: VecSet<PlayerReference>
+    },
+    #[serde(rename_all = "camelCase")]
     PlayerNominated{
         player_index: PlayerReference,
         players_voted: Vec<PlayerReference>
@@ -104,6 +113,15 @@ pub enum ChatMessageVariant {
 
     #[serde(rename_all = "camelCase")]
     PhaseFastForwarded,
+    #[serde(rename_all = "camelCase")]
+    PhaseTimeAdjusted{seconds: i32},
+    #[serde(rename_all = "camelCase")]
+    PhaseTimesChanged,
+    /// Only sent to the host who tried to set them
+    #[serde(rename_all = "camelCase")]
+    InvalidPhaseTimes,
+    #[serde(rename_all = "camelCase")]
+    Moderation{action: ModerationAction},
 
     /* Role-specific */
     #[serde(rename_all = "camelCase")]
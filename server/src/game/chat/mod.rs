@@ -9,6 +9,7 @@ pub use chat_message_variant::*;
 use crate::game::prelude::*;
 
 use crate::game::{components::player_component::PlayerComponent, player::PlayerReference, Game};
+use crate::{event_listener, game::event::registry::{EventRegistry, ListenerOrder}};
 
 
 pub type ChatComponent = PlayerComponent<ChatPlayerComponent>;
@@ -24,6 +25,11 @@ impl ChatPlayerComponent{
     }
 }
 impl ChatComponent{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(ChatComponent::on_validated_ability_input_received));
+        registry.subscribe(event_listener!(ChatComponent::on_whisper).order(ListenerOrder::Early));
+    }
+
     /// # Safety
     /// player_count is correct
     pub unsafe fn new(player_count: u8)->Self{
@@ -2,10 +2,16 @@ use crate::game::{
     components::{graves::grave::Grave, win_condition::WinCondition}, event::{on_phase_start::OnPhaseStart, on_tick::OnTick},
     game_conclusion::GameOverCheckPlayer, player::PlayerReference, Game
 };
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 pub struct Ascend;
 
 impl Ascend{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(Ascend::on_phase_start));
+        registry.subscribe(event_listener!(Ascend::on_tick));
+    }
+
     fn ascend_players(game: &mut Game){
         if game.game_is_over() {return}
         PlayerReference::all_players(game)
@@ -1,4 +1,4 @@
-use crate::{game::{Game, attack_power::AttackPower, chat::ChatMessageVariant, components::graves::grave::GraveDeathCause, event::on_midnight::OnMidnightFold, player::PlayerReference, prelude::{NightVisitsIterator, Visit, Visits}}, vec_set::VecSet};
+use crate::{game::{Game, attack_power::AttackPower, chat::ChatMessageVariant, components::{graves::grave::GraveDeathCause, night_trace::{NightTraceAttackResult, NightTraceEvent}}, event::on_midnight::OnMidnightFold, modifiers::ModifierID, player::PlayerReference, prelude::{NightVisitsIterator, Visit, Visits}}, vec_set::VecSet};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NightAttack<GK = GraveDeathCause> {
@@ -54,26 +54,36 @@ impl NightAttack<()> {
 
 impl NightAttack<GraveDeathCause> {
     pub fn rampage(self, game: &mut Game, fold: &mut OnMidnightFold, target: PlayerReference, filter_visit: impl FnMut(&Visit) -> bool) {
-        Visits::into_iter(fold)
+        let visitors: Vec<_> = Visits::into_iter(fold)
             .filter(filter_visit)
             .with_target(target)
             .with_direct()
             .map_visitor()
-            .for_each(|p|{
-                NightAttack {
-                    send_messages: self.send_messages,
-                    attackers: self.attackers.clone(),
-                    attack_power: self.attack_power,
-                    leave_calling_card: self.leave_calling_card,
-                    grave_killer: self.grave_killer.clone(),
-                }.attack(game, fold, p);
-            });
+            .collect();
+
+        for p in visitors {
+            NightAttack {
+                send_messages: self.send_messages,
+                attackers: self.attackers.clone(),
+                attack_power: self.attack_power,
+                leave_calling_card: self.leave_calling_card,
+                grave_killer: self.grave_killer.clone(),
+            }.attack(game, fold, p);
+        }
     }
 
     pub fn attack(&self, game: &mut Game, fold: &mut OnMidnightFold, defender: PlayerReference)->bool{
+        if game.day_number() == 1 && game.modifier_settings().is_enabled(ModifierID::NoNight1Attacks) {
+            self.trace(game, fold, defender, NightTraceAttackResult::Prevented);
+            return false;
+        }
 
         defender.set_night_attacked(fold, true);
-        if self.defense_check(game, fold, defender) {return false;}
+        if self.defense_check(game, fold, defender) {
+            self.trace(game, fold, defender, NightTraceAttackResult::Defended);
+            return false;
+        }
+        self.trace(game, fold, defender, NightTraceAttackResult::Succeeded);
 
         defender.push_night_grave_killers(fold, self.grave_killer.clone());
 
@@ -92,6 +102,16 @@ impl NightAttack<GraveDeathCause> {
         true
     }
 
+    fn trace(&self, game: &Game, fold: &mut OnMidnightFold, defender: PlayerReference, result: NightTraceAttackResult) {
+        fold.trace_event(|fold| NightTraceEvent::Attack {
+            attackers: self.attackers.clone(),
+            defender,
+            attack_power: self.attack_power,
+            defense_power: defender.night_defense(game, fold),
+            result
+        });
+    }
+
     pub fn defense_check(
         &self,
         game: &mut Game,
@@ -1,4 +1,5 @@
-use crate::game::{components::player_component::PlayerComponent, prelude::*};
+use crate::game::{components::{night_trace::NightTraceEvent, player_component::PlayerComponent}, prelude::*};
+use crate::{event_listener, game::event::registry::{EventRegistry, ListenerOrder}};
 
 pub type BlockedComponent = PlayerComponent<Blocked>;
 pub struct Blocked{
@@ -10,6 +11,12 @@ impl Blocked{
     }
 }
 impl BlockedComponent{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(BlockedComponent::on_phase_start));
+        registry.subscribe(event_listener!(BlockedComponent::on_player_roleblocked).order(ListenerOrder::Late));
+        registry.subscribe(event_listener!(BlockedComponent::on_visit_wardblocked).order(ListenerOrder::Late));
+    }
+
     /// # Safety
     /// player_count is correct
     pub unsafe fn new(player_count: u8)->Self{
@@ -32,11 +39,13 @@ impl BlockedComponent{
     }
 
     pub fn on_visit_wardblocked(game: &mut Game, event: &OnVisitWardblocked, fold: &mut OnMidnightFold, _priority: ()) {
+        fold.trace_event(|_| NightTraceEvent::Wardblocked { visit: event.visit });
         event.visit.visitor.set_night_blocked(fold, true);
         event.visit.visitor.push_night_message(fold, ChatMessageVariant::Wardblocked);
         Self::set_blocked(game, event.visit.visitor);
     }
     pub fn on_player_roleblocked(game: &mut Game, event: &OnPlayerRoleblocked, fold: &mut OnMidnightFold, _priority: ()) {
+        fold.trace_event(|_| NightTraceEvent::Roleblocked { player: event.player, invisible: event.invisible });
         event.player.set_night_blocked(fold, true);
         if !event.invisible {
             event.player.push_night_message(fold,
@@ -62,7 +71,7 @@ impl PlayerReference {
 
     fn pre_ward(&self, game: &mut Game, mut midnight_variables: &mut OnMidnightFold) -> Vec<PlayerReference> {
         let mut out = Vec::new();
-        for visit in Visits::into_iter(midnight_variables) {
+        for visit in Visits::into_iter(midnight_variables).collect::<Vec<_>>() {
             if visit.wardblock_immune {
                 continue;
             }
@@ -86,7 +95,7 @@ impl PlayerReference {
     }
     fn ward(&self, game: &mut Game, mut midnight_variables: &mut OnMidnightFold) -> Vec<PlayerReference> {
         let mut out = Vec::new();
-        for visit in Visits::into_iter(midnight_variables) {
+        for visit in Visits::into_iter(midnight_variables).collect::<Vec<_>>() {
             if visit.wardblock_immune {
                 continue;
             }
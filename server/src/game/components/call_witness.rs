@@ -1,4 +1,4 @@
-use std::{collections::HashSet, iter::once};
+use std::iter::once;
 
 use crate::{
     game::{
@@ -8,11 +8,17 @@ use crate::{
     },
     vec_set::VecSet
 };
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 
 pub struct CallWitness;
 
 impl CallWitness{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(CallWitness::on_phase_start));
+        registry.subscribe(event_listener!(CallWitness::on_validated_ability_input_received));
+    }
+
     pub fn send_player_chat_group_map(game: &Game) -> PlayerChatGroupMap {
         let mut out = PlayerChatGroupMap::new();
         for player in Self::witness_called(game){
@@ -55,14 +61,14 @@ impl CallWitness{
             .allow_players(allowed_players)
             .build_map()
     }
-    pub fn witness_called(game: &Game)->HashSet<PlayerReference>{
+    pub fn witness_called(game: &Game)->VecSet<PlayerReference>{
         if
             let PhaseState::Testimony{player_on_trial, ..} = game.phase_machine.current_state &&
             let Some(PlayerListSelection(players)) = (ControllerID::CallWitness { player: player_on_trial }).get_player_list_selection(game)
         {
             players.clone().into_iter().chain(once(player_on_trial)).collect()
         }else{
-            HashSet::new()
+            VecSet::new()
         }
     }
     pub fn on_validated_ability_input_received(game: &mut Game, event: &OnValidatedControllerInputReceived, _fold: &mut (), _priority: ()){
@@ -3,6 +3,7 @@ use rand::seq::IndexedRandom;
 use crate::game::{
     chat::ChatMessageVariant, components::{insider_group::InsiderGroupID, verdicts_today::VerdictsToday}, event::{on_any_death::OnAnyDeath, on_game_start::OnGameStart, on_remove_insider::OnRemoveInsider, on_role_switch::OnRoleSwitch}, player::PlayerReference, role::Role, Game
 };
+use crate::{event_listener, game::event::registry::{EventRegistry, ListenerOrder}};
 
 #[derive(Default, Debug, Clone)]
 pub struct Cult {
@@ -11,6 +12,13 @@ pub struct Cult {
     pub player_executed: bool
 }
 impl Cult{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(Cult::on_any_death).order(ListenerOrder::Early));
+        registry.subscribe(event_listener!(Cult::on_game_start));
+        registry.subscribe(event_listener!(Cult::on_remove_insider));
+        registry.subscribe(event_listener!(Cult::on_role_switch).order(ListenerOrder::Early));
+    }
+
     pub fn on_game_start(game: &mut Game, _event: &OnGameStart, _fold: &mut (), _priority: ()){
         Self::ensure_apostle_exists(game);
     }
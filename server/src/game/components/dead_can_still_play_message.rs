@@ -1,8 +1,13 @@
 use crate::game::{abilities_component::{ability_id::AbilityID, Abilities}, chat::ChatMessageVariant, event::on_any_death::OnAnyDeath, role::Role, Game};
+use crate::{event_listener, game::event::registry::{EventRegistry, ListenerOrder}};
 
 pub struct DeadCanStillPlayMessage;
 
 impl DeadCanStillPlayMessage {
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(DeadCanStillPlayMessage::on_any_death).order(ListenerOrder::Late));
+    }
+
     pub fn on_any_death(game: &mut Game, event: &OnAnyDeath, _fold: &mut (), _priority: ()) {
         if
             Abilities::ids(game)
@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use crate::game::{chat::ChatMessageVariant, event::{on_midnight::{OnMidnightFold, OnMidnight, OnMidnightPriority}, on_phase_start::OnPhaseStart}, phase::PhaseType, player::PlayerReference, Game};
 
 use super::insider_group::InsiderGroupID;
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 #[derive(Default)]
 pub struct Detained{
@@ -10,6 +11,11 @@ pub struct Detained{
     players: HashSet<PlayerReference>,
 }
 impl Detained{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(Detained::on_midnight));
+        registry.subscribe(event_listener!(Detained::on_phase_start));
+    }
+
     pub fn on_phase_start(game: &mut Game, event: &OnPhaseStart, _fold: &mut (), _priority: ()){
         if event.phase.phase() == PhaseType::Obituary {
             Detained::clear_detain(game);
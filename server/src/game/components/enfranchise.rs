@@ -4,12 +4,17 @@ use crate::game::{
 };
 
 use super::tags::Tags;
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 pub type EnfranchiseComponent = PlayerComponent<Option<EnfranchisePower>>;
 pub struct EnfranchisePower{
     power: u8
 } 
 impl EnfranchiseComponent{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(EnfranchiseComponent::on_game_start));
+    }
+
     /// # Safety
     /// player_count is correct
     pub unsafe fn new(num_players: u8) -> Self {
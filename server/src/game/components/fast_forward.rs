@@ -9,6 +9,7 @@ use crate::{
     },
     packet::ToClientPacket
 };
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 pub type FastForwardComponent = PlayerComponent<FastForwardSetting>;
 
@@ -22,6 +23,10 @@ pub enum FastForwardSetting{
 }
 
 impl FastForwardComponent {
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(FastForwardComponent::on_phase_start));
+    }
+
     /// # Safety
     /// player_count <= the games real player count
     pub unsafe fn new(num_players: u8) -> Self {
@@ -6,9 +6,15 @@ use crate::{
 };
 
 use super::tags::{TagSetID, Tags};
+use crate::{event_listener, game::event::registry::{EventRegistry, ListenerOrder}};
 
 pub struct ForfeitNominationVote;
 impl ForfeitNominationVote{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(ForfeitNominationVote::on_game_start).order(ListenerOrder::Late));
+        registry.subscribe(event_listener!(ForfeitNominationVote::on_phase_start));
+    }
+
     pub fn controller_parameters_map(game: &Game)->ControllerParametersMap {
         if
             !game.modifier_settings().is_enabled(ModifierID::ForfeitNominationVote)
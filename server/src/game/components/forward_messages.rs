@@ -1,10 +1,15 @@
 use crate::game::{chat::{ChatComponent, ChatMessageVariant}, controllers::{AvailableChatMessageSelection, ControllerID, ControllerParametersMap, ControllerSelection}, event::on_validated_ability_input_received::OnValidatedControllerInputReceived, phase::PhaseState, player::PlayerReference, Game};
 
 use super::insider_group::InsiderGroupID;
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 pub struct ForwardMessages;
 
 impl ForwardMessages{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(ForwardMessages::on_validated_ability_input_received));
+    }
+
     pub fn on_validated_ability_input_received(game: &mut Game, event: &OnValidatedControllerInputReceived, _fold: &mut (), _priority: ()){
         let (
             ControllerID::ForwardMessage{player},
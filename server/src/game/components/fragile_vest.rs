@@ -1,9 +1,14 @@
 use crate::{game::{attack_power::DefensePower, chat::ChatMessageVariant, event::on_midnight::{OnMidnightFold, OnMidnight, OnMidnightPriority}, player::PlayerReference, Game}, vec_set::VecSet};
 
 use super::player_component::PlayerComponent;
+use crate::{event_listener, game::event::registry::{EventRegistry, ListenerOrder}};
 
 pub type FragileVestsComponent = PlayerComponent<FragileVests>;
 impl FragileVestsComponent{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(FragileVestsComponent::on_midnight).order(ListenerOrder::Late));
+    }
+
     /// # Safety
     /// player_count is correct
     pub unsafe fn new(player_count: u8)->Self{
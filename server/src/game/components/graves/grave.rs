@@ -1,5 +1,4 @@
 use std::vec;
-use rand::rng;
 use rand::seq::SliceRandom;
 use serde::{Serialize, Deserialize};
 use crate::game::{event::on_midnight::OnMidnightFold, phase::PhaseType, player::PlayerReference, role::Role, role_list::RoleSet, Game};
@@ -39,6 +38,7 @@ pub enum GraveDeathCause {
     Role(Role),
     Suicide,
     Quit,
+    Modkill,
 }
 
 impl From<Role> for GraveDeathCause {
@@ -78,9 +78,9 @@ impl Grave{
     }
 
 
-    pub fn from_player_night(game: &Game, midnight_variables: &OnMidnightFold, player_ref: PlayerReference) -> Grave {
+    pub fn from_player_night(game: &mut Game, midnight_variables: &OnMidnightFold, player_ref: PlayerReference) -> Grave {
         let mut death_causes = player_ref.night_grave_killers(midnight_variables).clone();
-        death_causes.shuffle(&mut rng());
+        death_causes.shuffle(&mut game.rng);
         Grave {
             player: player_ref,
             died_phase: GravePhase::Night,
@@ -122,6 +122,34 @@ impl Grave{
         }
     }
 
+    pub fn from_player_quit(game: &Game, player_ref: PlayerReference) -> Grave {
+        Grave {
+            player: player_ref,
+            died_phase: GravePhase::from_phase_type(game.current_phase().phase()), 
+            day_number: game.phase_machine.day_number,
+            information: GraveInformation::Normal { 
+                role: player_ref.role(game), 
+                death_causes: vec![GraveDeathCause::Quit], 
+                alibi: player_ref.alibi(game).to_owned(), 
+                calling_cards: vec![]
+            }
+        }
+    }
+
+    pub fn from_player_modkill(game: &Game, player_ref: PlayerReference) -> Grave {
+        Grave {
+            player: player_ref,
+            died_phase: GravePhase::from_phase_type(game.current_phase().phase()), 
+            day_number: game.phase_machine.day_number,
+            information: GraveInformation::Normal { 
+                role: player_ref.role(game), 
+                death_causes: vec![GraveDeathCause::Modkill], 
+                alibi: player_ref.alibi(game).to_owned(), 
+                calling_cards: vec![]
+            }
+        }
+    }
+
     pub fn from_player_ascend(game: &Game, player_ref: PlayerReference) -> Grave {
         Grave {
             player: player_ref,
@@ -21,11 +21,13 @@ impl GraveReference{
         GraveReference { index }
     }
     pub fn deref(self, game: &Game)->&Grave{
+        debug_assert!((self.index as usize) < game.graves.graves.len(), "grave {} doesn't exist", self.index);
         unsafe {
             game.graves.graves.get_unchecked(self.index as usize)
         }
     }
     pub fn deref_mut(self, game: &mut Game)->&mut Grave{
+        debug_assert!((self.index as usize) < game.graves.graves.len(), "grave {} doesn't exist", self.index);
         unsafe {
             game.graves.graves.get_unchecked_mut(self.index as usize)
         }
@@ -1,4 +1,5 @@
 use crate::{game::prelude::*, packet::ToClientPacket};
+use crate::{event_listener, game::event::registry::EventRegistry};
 pub mod grave;
 pub mod grave_reference;
 
@@ -7,6 +8,10 @@ pub struct Graves{
     graves: Vec<Grave>,
 }
 impl Graves{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(Graves::on_grave_added));
+    }
+
     pub fn add_grave(game: &mut Game, grave: Grave) {
         if let Ok(grave_index) = game.graves.graves.len().try_into() {
             game.graves.graves.push(grave.clone());
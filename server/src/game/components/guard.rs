@@ -1,7 +1,12 @@
 use crate::game::{attack_power::DefensePower, chat::ChatMessageVariant, event::on_midnight::{OnMidnightFold, OnMidnight, OnMidnightPriority}, player::PlayerReference, Game};
+use crate::{event_listener, game::event::registry::{EventRegistry, ListenerOrder}};
 
 pub struct Guard;
 impl Guard{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(Guard::on_midnight).order(ListenerOrder::Late));
+    }
+
     pub fn on_midnight(game: &mut Game, _event: &OnMidnight, midnight_variables: &mut OnMidnightFold, priority: OnMidnightPriority){
         if priority != OnMidnightPriority::Investigative {return};
 
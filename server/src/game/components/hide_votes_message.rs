@@ -1,6 +1,7 @@
 use crate::game::{
     Game, chat::{ChatGroup, ChatMessageVariant}, event::on_tick::OnTick, modifiers::{hidden_nomination_votes::HiddenNominationVotes, hidden_verdict_votes::HiddenVerdictVotes}
 };
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 #[derive(Default)]
 pub struct HideVotesMessage{
@@ -8,6 +9,10 @@ pub struct HideVotesMessage{
 }
 
 impl HideVotesMessage{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(HideVotesMessage::on_tick));
+    }
+
     pub fn update_hidden_votes(game: &mut Game) {
         let should_be_hidden =
             HiddenNominationVotes::nomination_votes_are_hidden(game) ||
@@ -0,0 +1,100 @@
+use crate::{
+    game::{
+        chat::{ChatGroup, ChatMessageVariant}, components::graves::grave::Grave,
+        event::{before_phase_end::BeforePhaseEnd, on_validated_ability_input_received::OnValidatedControllerInputReceived},
+        game_client::GameClientLocation, phase::PhaseType, player::PlayerReference,
+        settings::InactivityAction, Game
+    },
+    vec_map::VecMap
+};
+use crate::{event_listener, game::event::registry::EventRegistry};
+
+/// Counts how many idle phases in a row each player has had.
+/// Any controller input, which includes chatting and voting, counts as activity.
+/// Only discussions are counted, and activity any time since the last discussion keeps a player active,
+/// so a player with nothing to do at night or between nominations isn't punished for it.
+#[derive(Default, Clone)]
+pub struct Inactivity {
+    players: VecMap<PlayerReference, PlayerActivity>,
+}
+
+#[derive(Default, Clone, Copy)]
+struct PlayerActivity {
+    idle_phases: u8,
+    active_this_phase: bool,
+}
+
+impl Inactivity {
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(Inactivity::before_phase_end));
+        registry.subscribe(event_listener!(Inactivity::on_validated_ability_input_received));
+    }
+
+    pub fn idle_phases(game: &Game, player: PlayerReference) -> u8 {
+        game.inactivity.players.get(&player).map(|activity| activity.idle_phases).unwrap_or_default()
+    }
+
+    fn phase_counts(phase: PhaseType) -> bool {
+        phase == PhaseType::Discussion
+    }
+
+    pub fn on_validated_ability_input_received(game: &mut Game, event: &OnValidatedControllerInputReceived, _fold: &mut (), _priority: ()) {
+        game.inactivity.players.insert(event.actor_ref, PlayerActivity { idle_phases: 0, active_this_phase: true });
+    }
+
+    pub fn before_phase_end(game: &mut Game, event: &BeforePhaseEnd, _fold: &mut (), _priority: ()) {
+        let settings = game.settings.inactivity.clone();
+        if settings.warning_after == 0 || !Self::phase_counts(event.phase) {return}
+
+        for player in PlayerReference::all_players(game) {
+            let mut activity = game.inactivity.players.get(&player).copied().unwrap_or_default();
+            let was_active = activity.active_this_phase;
+            activity.active_this_phase = false;
+
+            if !was_active && player.alive(game) && !player.is_disconnected(game) {
+                activity.idle_phases = activity.idle_phases.saturating_add(1);
+            }
+            game.inactivity.players.insert(player, activity);
+            if was_active {continue}
+
+            if activity.idle_phases == settings.warning_after {
+                player.add_private_chat_message(game, ChatMessageVariant::InactivityWarning {
+                    phases_left: match settings.action {
+                        InactivityAction::None => None,
+                        _ => Some(settings.action_after.saturating_sub(activity.idle_phases))
+                    }
+                });
+            }
+            if activity.idle_phases >= settings.action_after.max(settings.warning_after) {
+                Self::take_action(game, player, settings.action);
+            }
+        }
+    }
+
+    fn take_action(game: &mut Game, player: PlayerReference, action: InactivityAction) {
+        match action {
+            InactivityAction::None => {},
+            InactivityAction::Kill => {
+                game.add_message_to_chat_group(ChatGroup::All, ChatMessageVariant::PlayerRemovedForInactivity { player, replaced: false });
+                player.die_and_add_grave(game, Grave::from_player_quit(game, player));
+            }
+            InactivityAction::Replace => {
+                // If nobody is waiting yet, this is tried again after the next idle phase
+                let Some(replacement_id) = game.clients.iter()
+                    .find(|(_, client)| matches!(
+                        client.client_location,
+                        GameClientLocation::Spectator(spectator) if
+                            spectator.is_connected(game) &&
+                            spectator.deref(game).is_some_and(|spectator| spectator.waiting_to_replace)
+                    ))
+                    .map(|(id, _)| *id)
+                else {return};
+
+                if game.hand_over_seat(player, replacement_id) {
+                    game.inactivity.players.remove(&player);
+                    game.add_message_to_chat_group(ChatGroup::All, ChatMessageVariant::PlayerRemovedForInactivity { player, replaced: true });
+                }
+            }
+        }
+    }
+}
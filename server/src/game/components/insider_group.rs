@@ -11,6 +11,7 @@ use crate::{
     },
     packet::ToClientPacket, vec_set::VecSet
 };
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 #[derive(Debug)]
 pub struct InsiderGroups{
@@ -19,6 +20,10 @@ pub struct InsiderGroups{
     puppeteer: InsiderGroup
 }
 impl InsiderGroups{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(InsiderGroups::on_conceal_role));
+    }
+
     /// # Safety
     /// player_count is correct
     /// assignments contains all players
@@ -9,6 +9,7 @@ use super::{
     detained::Detained, fragile_vest::FragileVests, insider_group::InsiderGroupID, night_visits::Visits,
     player_component::PlayerComponent, tags::Tags
 };
+use crate::{event_listener, game::event::registry::{EventRegistry, ListenerOrder}};
 
 #[derive(Clone)]
 pub struct Mafia;
@@ -21,6 +22,20 @@ impl Game{
     }
 }
 impl Mafia{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(Mafia::on_add_insider));
+        registry.subscribe(event_listener!(Mafia::on_any_death).order(ListenerOrder::Early));
+        registry.subscribe(event_listener!(Mafia::on_controller_selection_changed).order(ListenerOrder::Early));
+        registry.subscribe(event_listener!(Mafia::on_game_start));
+        registry.subscribe(event_listener!(Mafia::on_midnight));
+        registry.subscribe(event_listener!(Mafia::on_player_possessed));
+        registry.subscribe(event_listener!(Mafia::on_player_roleblocked));
+        // Cult might convert someone when a player leaves it, so the mafia's tags are updated after
+        registry.subscribe(event_listener!(Mafia::on_remove_insider).order(ListenerOrder::Late));
+        registry.subscribe(event_listener!(Mafia::on_role_switch));
+        registry.subscribe(event_listener!(Mafia::on_visit_wardblocked));
+    }
+
     pub fn on_visit_wardblocked(_game: &mut Game, event: &OnVisitWardblocked, midnight_variables: &mut OnMidnightFold, _priority: ()){
         let visitor = event.visit.visitor;
         Visits::retain(midnight_variables, move |v|
@@ -152,6 +167,7 @@ impl Mafia{
             OnMidnightPriority::Kill => {
                 for backup_visit in Visits::into_iter(midnight_variables)
                     .with_tag(VisitTag::SyndicateBackupAttack)
+                    .collect::<Vec<_>>()
                 {
                     NightAttack::new()
                         .attackers([backup_visit.visitor])
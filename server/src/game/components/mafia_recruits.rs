@@ -1,5 +1,3 @@
-use std::collections::HashSet;
-
 use crate::{game::{
     Game, attack_power::AttackPower, chat::ChatMessageVariant, components::{attack::night_attack::NightAttack, insider_group::InsiderGroupID}, event::{
         on_add_insider::OnAddInsider, on_midnight::{OnMidnight, OnMidnightFold, OnMidnightPriority},
@@ -8,6 +6,7 @@ use crate::{game::{
 }, vec_set::VecSet};
 
 use super::{tags::Tags, win_condition::WinCondition};
+use crate::{event_listener, game::event::registry::{EventRegistry, ListenerOrder}};
 
 impl Game{
     pub fn mafia_recruits(&self)->&MafiaRecruits{
@@ -20,14 +19,20 @@ impl Game{
 
 #[derive(Default, Clone)]
 pub struct MafiaRecruits{
-    recruits: HashSet<PlayerReference>,
+    recruits: VecSet<PlayerReference>,
 }
 impl MafiaRecruits{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(MafiaRecruits::on_add_insider).order(ListenerOrder::Late));
+        registry.subscribe(event_listener!(MafiaRecruits::on_midnight));
+        registry.subscribe(event_listener!(MafiaRecruits::on_remove_insider).order(ListenerOrder::Last));
+    }
+
     pub fn recruit(game: &mut Game, midnight_variables: &mut OnMidnightFold, player: PlayerReference)->bool{
         let mut recruiter_recruits = game.mafia_recruits().clone();
 
         if InsiderGroupID::Mafia.contains_player(game, player) {return false;}
-        if !recruiter_recruits.recruits.insert(player){return false;}
+        if recruiter_recruits.recruits.insert(player).is_some() {return false;}
         Tags::add_tag(game, super::tags::TagSetID::SyndicateRecruit, player);
 
         game.set_recruiter_recruits(recruiter_recruits);
@@ -71,19 +76,19 @@ impl MafiaRecruits{
     pub fn is_recruited(game: &Game, player: PlayerReference)->bool{
         game.mafia_recruits().recruits.contains(&player)
     }
-    pub fn recruits(game: &Game)->HashSet<PlayerReference>{
+    pub fn recruits(game: &Game)->VecSet<PlayerReference>{
         PlayerReference::all_players(game)
             .filter(|p|
                 game.mafia_recruits().recruits.contains(p)
             )
             .collect()
     }
-    pub fn mafia_members(game: &Game)->HashSet<PlayerReference>{
+    pub fn mafia_members(game: &Game)->VecSet<PlayerReference>{
         PlayerReference::all_players(game)
             .filter(|p|InsiderGroupID::Mafia.contains_player(game, *p))
             .collect()
     }
-    pub fn mafia_and_recruits(game: &Game)->HashSet<PlayerReference>{
+    pub fn mafia_and_recruits(game: &Game)->VecSet<PlayerReference>{
         let mut mafia_and_recruits = MafiaRecruits::recruits(game);
         mafia_and_recruits.extend(MafiaRecruits::mafia_members(game));
         mafia_and_recruits
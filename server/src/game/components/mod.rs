@@ -9,9 +9,12 @@ pub mod detained;
 pub mod confused;
 pub mod forfeit_vote;
 pub mod night_visits;
+pub mod night_trace;
 pub mod synopsis;
 pub mod dead_can_still_play_message;
 pub mod nomination_controller;
+pub mod nomination_revote;
+pub mod vote_history;
 pub mod forward_messages;
 pub mod silenced;
 pub mod tags;
@@ -26,6 +29,9 @@ pub mod judgement_controller;
 pub mod aura;
 pub mod call_witness;
 pub mod fast_forward;
+pub mod inactivity;
+pub mod moderation;
+pub mod pause;
 pub mod role;
 pub mod player_component;
 pub mod pitchfork_item;
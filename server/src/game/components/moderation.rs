@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{
+        chat::{ChatGroup, ChatMessageVariant}, components::graves::grave::Grave,
+        game_client::GameClientLocation, phase::PhaseType, player::PlayerReference, role::Role, Game
+    },
+    room::RoomClientID
+};
+
+/// Actions the host takes against players during the game.
+/// Every action is announced to everyone and shows up in the synopsis.
+#[derive(Default, Clone)]
+pub struct Moderation {
+    records: Vec<ModerationRecord>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerationRecord {
+    pub day: u8,
+    pub phase: PhaseType,
+    pub action: ModerationAction,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub enum ModerationAction {
+    Modkill { player: PlayerReference },
+    ReplacePlayer { player: PlayerReference },
+    ForceReveal { player: PlayerReference, role: Role },
+}
+
+impl Moderation {
+    pub fn records(game: &Game) -> &[ModerationRecord] {
+        &game.moderation.records
+    }
+
+    /// Returns false if the player is already dead
+    pub fn modkill(game: &mut Game, player: PlayerReference) -> bool {
+        if !player.alive(game) {return false}
+
+        Self::record(game, ModerationAction::Modkill { player });
+        player.die_and_add_grave(game, Grave::from_player_modkill(game, player));
+        true
+    }
+
+    /// Every player learns the role of a living player
+    pub fn force_reveal(game: &mut Game, player: PlayerReference) -> bool {
+        if !player.alive(game) {return false}
+
+        Self::record(game, ModerationAction::ForceReveal { player, role: player.role(game) });
+        for other in PlayerReference::all_players(game) {
+            other.reveal_players_role(game, player);
+        }
+        true
+    }
+
+    /// Gives the seat of a player who isn't connected to a connected spectator.
+    /// The old client is removed, so it can no longer rejoin as that player.
+    pub fn replace_player(game: &mut Game, player_id: RoomClientID, replacement_id: RoomClientID) -> bool {
+        let Some(GameClientLocation::Player(player)) = game.clients.get(&player_id).map(|client| client.client_location.clone()) else {return false};
+        if player.is_connected(game) {return false}
+
+        if !game.hand_over_seat(player, replacement_id) {return false}
+        Self::record(game, ModerationAction::ReplacePlayer { player });
+        true
+    }
+
+    fn record(game: &mut Game, action: ModerationAction) {
+        game.moderation.records.push(ModerationRecord {
+            day: game.day_number(),
+            phase: game.current_phase().phase(),
+            action: action.clone(),
+        });
+        game.add_message_to_chat_group(ChatGroup::All, ChatMessageVariant::Moderation { action });
+    }
+}
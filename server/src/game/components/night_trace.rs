@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{
+        abilities_component::ability_id::AbilityID,
+        attack_power::{AttackPower, DefensePower},
+        event::on_midnight::{OnMidnight, OnMidnightFold, OnMidnightPriority, PlayerMidnightVariables},
+        modifiers::ModifierID,
+        player::PlayerReference,
+        Game
+    },
+    vec_set::VecSet
+};
+
+use super::night_visits::{visit::Visit, Visits};
+use crate::{event_listener, game::event::registry::{EventRegistry, ListenerOrder}};
+
+/// Step by step record of how each night resolved.
+/// Only recorded when the night breakdown modifier is enabled, because it is expensive.
+#[derive(Default, Clone)]
+pub struct NightTrace {
+    nights: Vec<TracedNight>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TracedNight {
+    pub night: u8,
+    /// Priorities where nothing happened are left out
+    pub steps: Vec<NightTraceStep>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NightTraceStep {
+    pub priority: OnMidnightPriority,
+    pub events: Vec<NightTraceEvent>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub enum NightTraceEvent {
+    AbilityActed { ability: AbilityID },
+    VisitAdded { visit: Visit },
+    VisitRemoved { visit: Visit },
+    VisitTransported { from: Visit, to: Visit },
+    Attack {
+        attackers: VecSet<PlayerReference>,
+        defender: PlayerReference,
+        attack_power: AttackPower,
+        defense_power: DefensePower,
+        result: NightTraceAttackResult,
+    },
+    Roleblocked { player: PlayerReference, invisible: bool },
+    Wardblocked { visit: Visit },
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum NightTraceAttackResult {
+    /// Night attacks were prevented for the whole night
+    Prevented,
+    Defended,
+    Succeeded,
+}
+
+/// The trace of the night that is currently resolving, kept in the midnight fold
+#[derive(Default, Clone, Debug)]
+pub struct NightTraceRecorder {
+    steps: Vec<NightTraceStep>,
+    visits: Vec<Visit>,
+}
+
+/// What the fold looked like before a listener ran, so the trace can tell if it did anything
+pub struct NightTraceSnapshot {
+    pub player_data: Vec<PlayerMidnightVariables>,
+    pub visit_ledger_len: usize,
+    pub event_count: usize,
+}
+
+impl NightTraceRecorder {
+    pub fn push_event(&mut self, event: NightTraceEvent) {
+        if let Some(step) = self.steps.last_mut() {
+            step.events.push(event);
+        }
+    }
+    pub fn insert_event(&mut self, index: usize, event: NightTraceEvent) {
+        if let Some(step) = self.steps.last_mut() {
+            step.events.insert(index.min(step.events.len()), event);
+        }
+    }
+    pub fn event_count(&self) -> usize {
+        self.steps.last().map_or(0, |step| step.events.len())
+    }
+
+    fn begin_step(&mut self, priority: OnMidnightPriority) {
+        self.steps.push(NightTraceStep { priority, events: Vec::new() });
+    }
+    fn end_step(&mut self, visits: Vec<Visit>) {
+        let mut removed = self.visits.clone();
+        let mut added = Vec::new();
+        for visit in visits.iter() {
+            if let Some(index) = removed.iter().position(|v| v == visit) {
+                removed.remove(index);
+            } else {
+                added.push(*visit);
+            }
+        }
+
+        for from in removed {
+            if let Some(index) = added.iter().position(|to| to.visitor == from.visitor && to.tag == from.tag) {
+                let to = added.remove(index);
+                self.push_event(NightTraceEvent::VisitTransported { from, to });
+            } else {
+                self.push_event(NightTraceEvent::VisitRemoved { visit: from });
+            }
+        }
+        for visit in added {
+            self.push_event(NightTraceEvent::VisitAdded { visit });
+        }
+        self.visits = visits;
+
+        if self.steps.last().is_some_and(|step| step.events.is_empty()) {
+            self.steps.pop();
+        }
+    }
+}
+
+impl NightTrace {
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(NightTrace::on_midnight_step_start).order(ListenerOrder::First));
+        registry.subscribe(event_listener!(NightTrace::on_midnight_step_end).order(ListenerOrder::Last));
+    }
+
+    pub fn nights(game: &Game) -> &[TracedNight] {
+        &game.night_trace.nights
+    }
+
+    pub fn recorder(game: &Game) -> Option<NightTraceRecorder> {
+        game.modifier_settings()
+            .is_enabled(ModifierID::NightBreakdown)
+            .then(NightTraceRecorder::default)
+    }
+
+    /// Runs before every other midnight listener
+    pub fn on_midnight_step_start(_game: &mut Game, _event: &OnMidnight, fold: &mut OnMidnightFold, priority: OnMidnightPriority) {
+        if let Some(trace) = fold.trace_mut() {
+            trace.begin_step(priority);
+        }
+    }
+
+    /// Runs after every other midnight listener
+    pub fn on_midnight_step_end(game: &mut Game, _event: &OnMidnight, fold: &mut OnMidnightFold, priority: OnMidnightPriority) {
+        if fold.trace_mut().is_none() {return}
+        let visits = Visits::into_iter(fold).collect();
+        if let Some(trace) = fold.trace_mut() {
+            trace.end_step(visits);
+        }
+
+        if priority == OnMidnightPriority::FinalizeNight && let Some(trace) = fold.take_trace() {
+            game.night_trace.nights.push(TracedNight {
+                night: game.day_number(),
+                steps: trace.steps,
+            });
+        }
+    }
+}
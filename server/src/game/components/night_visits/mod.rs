@@ -3,14 +3,18 @@ use std::{borrow::Borrow, fmt::Debug};
 use crate::game::prelude::*;
 use visit::*;
 
+/// The ledger is the source of truth, every event is replayed in order to get the visits.
+/// The result is kept materialized so queries don't have to replay the whole ledger.
+/// Queries borrow the materialized visits, so anything that changes the fold while going through them should collect them first.
 #[derive(Default, Clone)]
 pub struct Visits{
-    ledger: Vec<VisitLedgerEvent>
+    ledger: Vec<VisitLedgerEvent>,
+    /// Always equal to replaying every event in the ledger
+    materialized: Vec<Visit>,
 }
 impl Debug for Visits{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let visits = self.calculate_visits();
-        f.debug_struct("Visits").field("ledger", &visits).finish()
+        f.debug_struct("Visits").field("ledger", &self.materialized).finish()
     }
 }
 
@@ -49,53 +53,76 @@ impl VisitLedgerEvent{
 
 
 impl Visits{
+    /// Replaces the generator of every event with this id.
+    /// Later events might depend on what this one did, so the whole ledger is replayed.
     pub fn replace_ledger_event_id<T: Fn(&mut Vec<Visit>) + Send + Clone + 'static>(
         midnight_variables: &mut OnMidnightFold,
         id: VisitLedgerEventID,
         generator: T
     ){
-        midnight_variables
-            .visits_mut()
-            .ledger
+        let visits = midnight_variables.visits_mut();
+        if !visits.ledger.iter().any(|event|event.id == Some(id)) {return}
+
+        visits.ledger
             .iter_mut()
             .filter(|event|event.id == Some(id))
             .for_each(|event|*event = VisitLedgerEvent::new_id(id, generator.clone()));
+
+        visits.materialized = Self::calculate_visits(&visits.ledger);
     }
     pub fn push_ledger_event_id<T: Fn(&mut Vec<Visit>) + Send + Clone + 'static>(midnight_variables: &mut OnMidnightFold, id: VisitLedgerEventID, generator: T){
-        midnight_variables.visits_mut().ledger.push(VisitLedgerEvent::new_id(id, generator));
+        midnight_variables.visits_mut().push(VisitLedgerEvent::new_id(id, generator));
     }
     pub fn push_ledger_event<T: Fn(&mut Vec<Visit>) + Send + Clone + 'static>(midnight_variables: &mut OnMidnightFold, generator: T){
-        midnight_variables.visits_mut().ledger.push(VisitLedgerEvent::new(generator));
+        midnight_variables.visits_mut().push(VisitLedgerEvent::new(generator));
     }
     pub fn add_visit(midnight_variables: &mut OnMidnightFold, visits: Visit){
-        midnight_variables.visits_mut().ledger.push(VisitLedgerEvent::new(move |v|
+        midnight_variables.visits_mut().push(VisitLedgerEvent::new(move |v|
             v.push(visits)
         ));
     }
     pub fn add_visits(midnight_variables: &mut OnMidnightFold, visits: impl Iterator<Item = Visit> + Clone + Send + 'static){
-        midnight_variables.visits_mut().ledger.push(VisitLedgerEvent::new(move |v|
+        midnight_variables.visits_mut().push(VisitLedgerEvent::new(move |v|
             v.extend(visits.clone())
         ));
     }
 
     //Only keeps elements where f is true
     pub fn retain(midnight_variables: &mut OnMidnightFold, f: impl FnMut(&Visit) -> bool + Clone + Send + 'static){
-        midnight_variables.visits_mut().ledger.push(VisitLedgerEvent::new(move |v|
+        midnight_variables.visits_mut().push(VisitLedgerEvent::new(move |v|
             v.retain(f.clone())
         ));
     }
 
-    fn calculate_visits(&self)->Vec<Visit> {
+    pub fn ledger_len(&self)->usize {
+        self.ledger.len()
+    }
+
+    /// Appending to the ledger only needs the new event applied to what is already materialized
+    fn push(&mut self, event: VisitLedgerEvent) {
+        (event.generator)(&mut self.materialized);
+        self.ledger.push(event);
+    }
+
+    /// Replays the whole ledger, so it's only checked once a night has finished resolving
+    pub fn debug_assert_materialized(&self) {
+        debug_assert!(
+            self.materialized == Self::calculate_visits(&self.ledger),
+            "materialized visits don't match a replay of the ledger"
+        );
+    }
+
+    fn calculate_visits(ledger: &[VisitLedgerEvent])->Vec<Visit> {
         let mut out = Vec::new();
-        for event in self.ledger.iter() {
+        for event in ledger.iter() {
             (event.generator)(&mut out);
         }
         out
     }
 
 
-    pub fn into_iter(midnight_variables: &OnMidnightFold) -> impl Iterator<Item = Visit> + use<> {
-        midnight_variables.visits().calculate_visits().into_iter()
+    pub fn into_iter(midnight_variables: &OnMidnightFold) -> impl Iterator<Item = Visit> + '_ {
+        midnight_variables.visits().materialized.iter().copied()
     }
     // pub fn iter(midnight_variables: &OnMidnightFold) -> impl Iterator<Item = &Visit> {
     //     midnight_variables.visits().iter()
@@ -114,6 +141,8 @@ impl PlayerReference{
             .with_target(self)
             .with_direct()
             .map_visitor()
+            .collect::<Vec<_>>()
+            .into_iter()
     }
 
     pub fn tracker_seen_players(self, midnight_variables: &OnMidnightFold) -> impl Iterator<Item = PlayerReference> {
@@ -5,11 +5,16 @@ use crate::game::{
         }, chat::{ChatGroup, ChatMessageVariant}, event::on_validated_ability_input_received::OnValidatedControllerInputReceived, modifiers::{hidden_nomination_votes::HiddenNominationVotes, ModifierID}, player::PlayerReference, Game
     };
 
-use super::forfeit_vote::ForfeitNominationVote;
+use super::{forfeit_vote::ForfeitNominationVote, nomination_revote::NominationRevote};
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 pub struct NominationController;
 
 impl NominationController{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(NominationController::on_validated_ability_input_received));
+    }
+
     pub fn controller_parameters_map(game: &mut Game)->ControllerParametersMap{
         ControllerParametersMap::combine(
             PlayerReference::all_players(game)
@@ -20,9 +25,12 @@ impl NominationController{
         ControllerParametersMap::builder(game)
             .id(crate::game::controllers::ControllerID::Nominate { player: actor })
             .available_selection(AvailablePlayerListSelection {
-                available_players: PlayerReference::all_players(game).filter(|p|p.alive(game)).collect(),
+                available_players: PlayerReference::all_players(game)
+                    .filter(|p|p.alive(game))
+                    .filter(|p|NominationRevote::candidates(game).is_none_or(|candidates| candidates.contains(p)))
+                    .collect(),
                 can_choose_duplicates: false,
-                max_players: Some(1)
+                max_players: if game.modifier_settings().is_enabled(ModifierID::ApprovalNominations) {None} else {Some(1)}
             })
             .add_grayed_out_condition(
                 !actor.alive(game) ||
@@ -38,10 +46,18 @@ impl NominationController{
             event.input.get_player_list_selection_if_id(ControllerID::nominate(event.actor_ref)) else {return};
 
         if !HiddenNominationVotes::nomination_votes_are_hidden(game) {
-            game.add_message_to_chat_group(ChatGroup::All, ChatMessageVariant::Voted{
-                voter: event.actor_ref, 
-                votee: voted.first().copied()
-            });
+            let message = if game.modifier_settings().is_enabled(ModifierID::ApprovalNominations) {
+                ChatMessageVariant::VotedForPlayers{
+                    voter: event.actor_ref, 
+                    votees: voted.iter().copied().collect()
+                }
+            } else {
+                ChatMessageVariant::Voted{
+                    voter: event.actor_ref, 
+                    votee: voted.first().copied()
+                }
+            };
+            game.add_message_to_chat_group(ChatGroup::All, message);
         }
 
         game.count_nomination_and_start_trial(
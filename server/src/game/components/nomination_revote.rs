@@ -0,0 +1,20 @@
+use crate::{game::{player::PlayerReference, Game}, vec_set::VecSet};
+
+/// The players that can be nominated in a plurality revote
+#[derive(Default, Clone)]
+pub struct NominationRevote {
+    candidates: Option<VecSet<PlayerReference>>,
+}
+
+impl NominationRevote {
+    pub fn candidates(game: &Game) -> Option<&VecSet<PlayerReference>> {
+        game.nomination_revote.candidates.as_ref()
+    }
+    pub fn start(game: &mut Game, candidates: VecSet<PlayerReference>) {
+        game.nomination_revote.candidates = Some(candidates);
+    }
+    /// Returns true if the nomination that just ended was a revote
+    pub fn finish(game: &mut Game) -> bool {
+        game.nomination_revote.candidates.take().is_some()
+    }
+}
@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use crate::{game::Game, packet::ToClientPacket};
+
+/// While the game is paused the phase timer is frozen,
+/// and players can't use any controllers, which includes chatting.
+#[derive(Default)]
+pub struct Pause {
+    paused: bool,
+    /// Time left before the game resumes by itself
+    resume_countdown: Option<Duration>,
+}
+
+impl Pause {
+    pub const MAX_RESUME_COUNTDOWN: u16 = 60;
+
+    pub fn is_paused(game: &Game) -> bool {
+        game.pause.paused
+    }
+
+    pub fn pause(game: &mut Game) {
+        if game.game_is_over() {return}
+
+        game.pause.paused = true;
+        game.pause.resume_countdown = None;
+        Self::send_to_all(game);
+    }
+
+    /// Resumes straight away, or after a countdown if one is given
+    pub fn resume(game: &mut Game, countdown: Option<u16>) {
+        if !game.pause.paused {return}
+
+        match countdown.filter(|seconds| *seconds > 0) {
+            Some(seconds) => {
+                game.pause.resume_countdown = Some(Duration::from_secs(
+                    seconds.min(Self::MAX_RESUME_COUNTDOWN).into()
+                ));
+            }
+            None => {
+                game.pause.paused = false;
+                game.pause.resume_countdown = None;
+            }
+        }
+        Self::send_to_all(game);
+    }
+
+    /// Called instead of the regular game tick while the game is paused
+    pub fn tick(game: &mut Game, time_passed: Duration) {
+        let Some(countdown) = game.pause.resume_countdown else {return};
+
+        match countdown.saturating_sub(time_passed) {
+            Duration::ZERO => Self::resume(game, None),
+            remaining => game.pause.resume_countdown = Some(remaining)
+        }
+    }
+
+    pub fn packet(game: &Game) -> ToClientPacket {
+        ToClientPacket::GamePaused {
+            paused: game.pause.paused,
+            resume_in: game.pause.resume_countdown.map(|countdown| countdown.as_secs().try_into().unwrap_or(u16::MAX))
+        }
+    }
+
+    fn send_to_all(game: &mut Game) {
+        game.send_packet_to_all(Self::packet(game));
+    }
+}
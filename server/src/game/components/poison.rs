@@ -1,6 +1,7 @@
 use crate::game::{
     Game, chat::ChatMessageVariant, components::attack::night_attack::NightAttack, event::on_midnight::{OnMidnight, OnMidnightFold, OnMidnightPriority}, player::PlayerReference,
 };
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 impl Game {
     pub fn poison(&self)->&Poison{
@@ -29,6 +30,10 @@ pub enum PoisonAlert {
 }
 
 impl Poison{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(Poison::on_midnight));
+    }
+
     pub fn poison_player(
         game: &mut Game,
         midnight_variables: &mut OnMidnightFold,
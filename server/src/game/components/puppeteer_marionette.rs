@@ -1,10 +1,9 @@
-use std::collections::HashSet;
-
 use crate::{game::{
     Game, attack_power::AttackPower, chat::ChatMessageVariant, components::attack::night_attack::NightAttack, event::{on_add_insider::OnAddInsider, on_midnight::{OnMidnight, OnMidnightFold, OnMidnightPriority}, on_remove_insider::OnRemoveInsider}, game_conclusion::GameConclusion, player::PlayerReference, role::Role
 }, vec_set::VecSet};
 
 use super::{insider_group::InsiderGroupID, tags::Tags, win_condition::WinCondition};
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 impl Game{
     pub fn puppeteer_marionette(&self)->&PuppeteerMarionette{
@@ -17,14 +16,20 @@ impl Game{
 
 #[derive(Default, Clone)]
 pub struct PuppeteerMarionette{
-    marionettes: HashSet<PlayerReference>,
+    marionettes: VecSet<PlayerReference>,
 }
 impl PuppeteerMarionette{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(PuppeteerMarionette::on_add_insider));
+        registry.subscribe(event_listener!(PuppeteerMarionette::on_midnight));
+        registry.subscribe(event_listener!(PuppeteerMarionette::on_remove_insider));
+    }
+
     pub fn string(game: &mut Game, midnight_variables: &mut OnMidnightFold, player: PlayerReference)->bool{
         let mut puppeteer_marionette = game.puppeteer_marionette().clone();
 
         if player.role(game) == Role::Puppeteer {return false;}
-        if !puppeteer_marionette.marionettes.insert(player){return false;}
+        if puppeteer_marionette.marionettes.insert(player).is_some() {return false;}
         Tags::add_tag(game, super::tags::TagSetID::PuppeteerMarionette, player);
 
         game.set_puppeteer_marionette(puppeteer_marionette);
@@ -68,19 +73,19 @@ impl PuppeteerMarionette{
     pub fn is_marionette(game: &Game, player: PlayerReference)->bool{
         game.puppeteer_marionette().marionettes.contains(&player)
     }
-    pub fn marionettes(game: &Game)->HashSet<PlayerReference>{
+    pub fn marionettes(game: &Game)->VecSet<PlayerReference>{
         PlayerReference::all_players(game)
             .filter(|p|
                 game.puppeteer_marionette().marionettes.contains(p)
             )
             .collect()
     }
-    pub fn puppeteers(game: &Game)->HashSet<PlayerReference>{
+    pub fn puppeteers(game: &Game)->VecSet<PlayerReference>{
         PlayerReference::all_players(game)
             .filter(|p|p.role(game)==Role::Puppeteer)
             .collect()
     }
-    pub fn marionettes_and_puppeteer(game: &Game)->HashSet<PlayerReference>{
+    pub fn marionettes_and_puppeteer(game: &Game)->VecSet<PlayerReference>{
         let mut marionettes_and_puppeteer = PuppeteerMarionette::marionettes(game);
         marionettes_and_puppeteer.extend(PuppeteerMarionette::puppeteers(game));
         marionettes_and_puppeteer
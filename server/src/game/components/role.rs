@@ -1,9 +1,15 @@
 use crate::{game::{
     Assignments, Game, abilities::role_abilities::RoleAbility, abilities_component::{ability::Ability, ability_id::AbilityID}, chat::ChatMessageVariant, components::player_component::PlayerComponent, event::{AsInvokable as _, Invokable as _, on_ability_edit::OnAbilityEdit, on_role_switch::OnRoleSwitch}, player::PlayerReference, prelude::{OnAbilityCreation, OnAbilityCreationFold, OnAbilityCreationPriority}, role::{Role, RoleState}
 }, packet::ToClientPacket};
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 pub type RoleComponent = PlayerComponent::<Role>;
 impl RoleComponent{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(RoleComponent::on_ability_creation));
+        registry.subscribe(event_listener!(RoleComponent::on_ability_edit));
+    }
+
     /// # Safety
     /// num_players must be correct
     pub unsafe fn new(num_players: u8, assignments: &Assignments) -> Self {
@@ -4,11 +4,16 @@ use crate::{
         player::PlayerReference, role::Role, Game
     }, packet::ToClientPacket, vec_map::VecMap, vec_set::VecSet
 };
+use crate::{event_listener, game::event::registry::{EventRegistry, ListenerOrder}};
 
 pub struct RevealedPlayers(VecSet<PlayerReference>);
 pub type RevealedPlayersComponent = PlayerComponent<RevealedPlayers>;
 
 impl RevealedPlayersComponent {
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(RevealedPlayersComponent::on_role_switch).order(ListenerOrder::Early));
+    }
+
     /// # Safety
     /// num_players must be correct
     pub unsafe fn new(num_players: u8) -> Self {
@@ -6,6 +6,7 @@ use crate::{
     },
     vec_set::VecSet
 };
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 impl Game {
     fn silenced(&self)->&Silenced{
@@ -20,6 +21,10 @@ pub struct Silenced {
     silenced_players: VecSet<PlayerReference>,
 }
 impl Silenced {
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(Silenced::on_phase_start));
+    }
+
     pub fn silence_night(game: &mut Game, midnight_variables: &mut OnMidnightFold, player: PlayerReference) {
         game.silenced_mut().silenced_players.insert(player);
 
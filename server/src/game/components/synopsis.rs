@@ -4,13 +4,19 @@ use serde::{Deserialize, Serialize};
 
 use crate::{game::{components::insider_group::InsiderGroupID, event::{on_convert::OnConvert, on_role_switch::OnRoleSwitch}, game_conclusion::GameConclusion, phase::PhaseType, player::PlayerReference, role::Role, role_outline_reference::RoleOutlineReference, Game}, vec_set::VecSet};
 
-use super::win_condition::WinCondition;
+use super::{moderation::{Moderation, ModerationRecord}, night_trace::{NightTrace, TracedNight}, vote_history::{VoteHistory, VoteHistoryEntry}, win_condition::WinCondition};
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 pub struct SynopsisTracker {
     player_synopses: Vec<PartialPlayerSynopsis>
 }
 
 impl SynopsisTracker {
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(SynopsisTracker::on_convert));
+        registry.subscribe(event_listener!(SynopsisTracker::on_role_switch));
+    }
+
     pub fn new(num_players: u8) -> Self {
         SynopsisTracker {
             player_synopses: (0..num_players).map(|_|
@@ -33,6 +39,9 @@ impl SynopsisTracker {
                         conclusion
                     )
                 ).collect(),
+            vote_history: VoteHistory::entries(game).to_vec(),
+            moderation: Moderation::records(game).to_vec(),
+            night_breakdown: NightTrace::nights(game).to_vec(),
             conclusion
         }
     }
@@ -78,6 +87,10 @@ impl SynopsisTracker {
 #[serde(rename_all = "camelCase")]
 pub struct Synopsis {
     player_synopses: Vec<PlayerSynopsis>,
+    vote_history: Vec<VoteHistoryEntry>,
+    moderation: Vec<ModerationRecord>,
+    /// Empty unless the night breakdown modifier was enabled
+    night_breakdown: Vec<TracedNight>,
     conclusion: GameConclusion,
 }
 
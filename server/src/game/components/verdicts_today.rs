@@ -1,4 +1,5 @@
 use crate::{game::{event::{before_phase_end::BeforePhaseEnd, on_phase_start::OnPhaseStart}, phase::{PhaseState, PhaseType}, player::PlayerReference, verdict::Verdict, Game}, vec_map::VecMap, vec_set::VecSet};
+use crate::{event_listener, game::event::registry::{EventRegistry, ListenerOrder}};
 
 #[derive(Default, Clone)]
 pub struct VerdictsToday{
@@ -7,6 +8,11 @@ pub struct VerdictsToday{
 }
 
 impl VerdictsToday{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(VerdictsToday::before_phase_end).order(ListenerOrder::Early));
+        registry.subscribe(event_listener!(VerdictsToday::on_phase_start));
+    }
+
     pub fn new()->Self{
         Self{
             guilties: VecMap::new(),
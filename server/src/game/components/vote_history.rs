@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{
+        controllers::{ControllerID, IntegerSelection, PlayerListSelection},
+        event::on_validated_ability_input_received::OnValidatedControllerInputReceived,
+        phase::PhaseType, player::PlayerReference, verdict::Verdict, Game
+    },
+    vec_set::VecSet
+};
+use crate::{event_listener, game::event::registry::EventRegistry};
+
+/// Every nomination and verdict change made during the game, in order.
+/// Only final votes show up in chat, so this is the only record of how votes moved.
+#[derive(Default, Clone)]
+pub struct VoteHistory {
+    entries: Vec<VoteHistoryEntry>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VoteHistoryEntry {
+    pub day: u8,
+    pub phase: PhaseType,
+    /// Seconds since the phase started when the vote was changed, not counting time the game was paused
+    pub seconds_into_phase: u16,
+    pub voter: PlayerReference,
+    pub vote: VoteHistoryVote,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub enum VoteHistoryVote {
+    Nomination { votees: VecSet<PlayerReference> },
+    Verdict { verdict: Verdict },
+}
+
+impl VoteHistory {
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(VoteHistory::on_validated_ability_input_received));
+    }
+
+    pub fn entries(game: &Game) -> &[VoteHistoryEntry] {
+        &game.vote_history.entries
+    }
+
+    pub fn entries_on_day(game: &Game, day: u8) -> impl Iterator<Item = &VoteHistoryEntry> {
+        game.vote_history.entries.iter().filter(move |entry| entry.day == day)
+    }
+
+    pub fn on_validated_ability_input_received(game: &mut Game, event: &OnValidatedControllerInputReceived, _fold: &mut (), _priority: ()) {
+        let vote = if let Some(PlayerListSelection(votees)) =
+            event.input.get_player_list_selection_if_id(ControllerID::nominate(event.actor_ref))
+        {
+            VoteHistoryVote::Nomination { votees: votees.into_iter().collect() }
+        } else if let Some(IntegerSelection(_)) =
+            event.input.get_integer_selection_if_id(ControllerID::Judge { player: event.actor_ref })
+        {
+            VoteHistoryVote::Verdict { verdict: event.actor_ref.verdict(game) }
+        } else {
+            return
+        };
+
+        let entry = VoteHistoryEntry {
+            day: game.day_number(),
+            phase: game.current_phase().phase(),
+            seconds_into_phase: game.phase_machine.time_elapsed.as_secs().try_into().unwrap_or(u16::MAX),
+            voter: event.actor_ref,
+            vote,
+        };
+        game.vote_history.entries.push(entry);
+    }
+}
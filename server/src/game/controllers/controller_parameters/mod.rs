@@ -44,6 +44,9 @@ impl ControllerParameters{
     pub fn validate_selection(&self, game: &Game, selection: &ControllerSelection)->bool{
         self.available.validate_selection(game, selection)
     }
+    pub fn available(&self)->&AvailableControllerSelection{
+        &self.available
+    }
     pub fn default_selection(&self)->&ControllerSelection{
         &self.default_selection
     }
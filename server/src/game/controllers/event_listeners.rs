@@ -1,12 +1,20 @@
 use crate::{
     game::{
-        Game, controllers::{ControllerID, ControllerInput, Controllers}, event::{
+        Game, components::pause::Pause, controllers::{ControllerID, ControllerInput, Controllers}, event::{
             AsInvokable as _, Invokable as _, on_controller_changed::OnControllerChanged, on_controller_input_received::OnControllerInputReceived, on_phase_start::OnPhaseStart, on_tick::OnTick, on_validated_ability_input_received::OnValidatedControllerInputReceived
         }
     }, packet::ToClientPacket, vec_set::VecSet
 };
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 impl Controllers{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(Controllers::on_controller_input_received));
+        registry.subscribe(event_listener!(Controllers::on_phase_start));
+        registry.subscribe(event_listener!(Controllers::on_tick));
+        registry.subscribe(event_listener!(Controllers::send_controller_to_client));
+    }
+
     pub fn on_controller_input_received(
         game: &mut Game,
         event: &OnControllerInputReceived,
@@ -18,6 +26,8 @@ impl Controllers{
         } = event.input.clone();
         let actor = event.actor_ref;
 
+        if Pause::is_paused(game) {return}
+
         if !Self::set_selection_in_controller(game, Some(actor), id.clone(), incoming_selection.clone(), false) {
             return
         }
@@ -17,7 +17,7 @@ use crate::{
             nomination_controller::NominationController
         }, event::{
             on_controller_changed::OnControllerChanged, AsInvokable as _, Invokable as _,
-        }, player::PlayerReference, Game
+        }, modifiers::vote_lock::VoteLock, player::PlayerReference, Game
     },
     vec_map::VecMap
 };
@@ -160,6 +160,7 @@ impl Controllers{
 
         parameters.validate_selection(game, selection) &&   //parameters say its valid
         (overwrite_gray_out || !parameters.grayed_out()) && //not grayed out
+        (overwrite_gray_out || !VoteLock::controller_is_locked(game, id)) &&   //votes aren't locked
         actor.is_none_or(|p|parameters.allowed_players().contains(&p)) &&   //actor is allowed
         (*saved_selection != *selection || *selection == ControllerSelection::Unit(UnitSelection))  //Something is actually changing (i think this can be removed?)
     }
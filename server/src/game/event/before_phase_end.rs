@@ -1,7 +1,5 @@
 use crate::game::{
-    abilities_component::Abilities,
-    components::verdicts_today::VerdictsToday,
-    event::EventData, modifiers::ModifierSettings,
+    event::EventData,
     phase::PhaseType
 };
 
@@ -18,10 +16,4 @@ impl EventData for BeforePhaseEnd{
     type FoldValue = ();
 
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        VerdictsToday::before_phase_end,
-        Abilities::before_phase_end,
-        ModifierSettings::before_phase_end,
-    ]}
 }
\ No newline at end of file
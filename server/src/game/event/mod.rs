@@ -25,6 +25,7 @@ pub(super) mod on_ability_edit;
 pub(super) mod on_player_possessed;
 
 pub mod prelude;
+pub mod registry;
 
 pub trait EventPriority: Sized + Copy {
     fn values() -> Vec<Self>;
@@ -36,20 +37,29 @@ pub trait EventPriority: Sized + Copy {
 /// // Event listener type
 /// // pub type EventListenerFunction<E: Event> = fn(&mut Game, &E, &mut E::FoldValue, E::Priority);
 /// 
-pub trait EventData: Sized {
+/// Listeners subscribe to events in the game's [`registry::EventRegistry`]
+pub trait EventData: Sized + 'static {
     type FoldValue;
     type Priority: EventPriority;
-
-    fn listeners() -> Vec<EventListenerFunction<Self>>;
 }
 pub trait Invokable{
     fn invoke(self, game: &mut Game)->Self;
 }
 impl<E: EventData> Invokable for (&E, &mut E::FoldValue) {
     fn invoke(self, game: &mut Game) -> Self {
+        let listeners = game.event_registry.listeners::<E>();
         for priority in E::Priority::values() {
-            for listener in E::listeners() {
-                listener(game, self.0, self.1, priority);
+            #[cfg(any(test, feature = "test-kit"))]
+            if game.event_registry.is_shuffled::<E>() {
+                let mut shuffled = listeners.to_vec();
+                game.event_registry.shuffle_within_order(&mut shuffled);
+                for listener in shuffled {
+                    (listener.function)(game, self.0, self.1, priority);
+                }
+                continue;
+            }
+            for listener in listeners.iter() {
+                (listener.function)(game, self.0, self.1, priority);
             }
         }
         self
@@ -74,7 +84,8 @@ macro_rules! event_priority {
             $($variant:ident),*
         }
     ) => {
-        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        #[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
         pub enum $name {
             $($variant),*
         }
@@ -1,4 +1,4 @@
-use crate::{event_priority, game::{abilities_component::{Abilities, ability::Ability, ability_id::AbilityID}, components::role::RoleComponent, event::EventData}};
+use crate::{event_priority, game::{abilities_component::{ability::Ability, ability_id::AbilityID}, event::EventData}};
 
 pub struct OnAbilityCreation{
     pub id: AbilityID,
@@ -15,11 +15,6 @@ pub struct OnAbilityCreationFold{
 impl EventData for OnAbilityCreation{
     type FoldValue = OnAbilityCreationFold;
     type Priority = OnAbilityCreationPriority;
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        RoleComponent::on_ability_creation,
-        Abilities::on_ability_creation,
-    ]}
 }
 impl OnAbilityCreation{
     pub fn new(id: AbilityID, ability: Ability)->(OnAbilityCreation, OnAbilityCreationFold){
@@ -1,5 +1,5 @@
 use crate::{event_priority, game::{
-    abilities_component::{ability_id::AbilityID, Abilities}, event::EventData
+    abilities_component::ability_id::AbilityID, event::EventData
 }};
 event_priority!(OnAbilityDeletionPriority{
     BeforeSideEffect,
@@ -18,8 +18,4 @@ impl OnAbilityDeletion{
 impl EventData for OnAbilityDeletion {
     type FoldValue = ();
     type Priority = OnAbilityDeletionPriority;
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        Abilities::on_ability_deletion
-    ]}
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::game::{abilities_component::{ability::Ability, ability_id::AbilityID}, components::role::RoleComponent, event::EventData};
+use crate::game::{abilities_component::{ability::Ability, ability_id::AbilityID}, event::EventData};
 
 pub struct OnAbilityEdit{
     pub id: AbilityID,
@@ -7,10 +7,6 @@ pub struct OnAbilityEdit{
 impl EventData for OnAbilityEdit{
     type FoldValue = ();
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        RoleComponent::on_ability_edit
-    ]}
 }
 impl OnAbilityEdit{
     pub fn new(id: AbilityID, new_ability: Option<Ability>)->(Self, ()){
@@ -1,8 +1,5 @@
 use crate::game::{
-    abilities_component::Abilities, components::{
-        insider_group::InsiderGroupID, mafia::Mafia,
-        mafia_recruits::MafiaRecruits, puppeteer_marionette::PuppeteerMarionette
-    }, player::PlayerReference
+    components::insider_group::InsiderGroupID, player::PlayerReference
 };
 use super::EventData;
 
@@ -24,13 +21,4 @@ impl OnAddInsider {
 impl EventData for OnAddInsider {
     type FoldValue = ();
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {
-        vec![
-            Abilities::on_add_insider,
-            PuppeteerMarionette::on_add_insider,
-            Mafia::on_add_insider,
-            MafiaRecruits::on_add_insider,
-        ]
-    }
 }
\ No newline at end of file
@@ -1,9 +1,5 @@
 use crate::game::{
-    abilities_component::Abilities,
-    components::{
-        cult::Cult, dead_can_still_play_message::DeadCanStillPlayMessage, mafia::Mafia
-    },
-    event::EventData, modifiers::ModifierSettings, player::PlayerReference,
+    event::EventData, player::PlayerReference,
 };
 
 #[must_use = "Event must be invoked"]
@@ -19,12 +15,4 @@ impl EventData for OnAnyDeath{
     type FoldValue = ();
 
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        Mafia::on_any_death,
-        Cult::on_any_death,
-        ModifierSettings::on_any_death,
-        Abilities::on_any_death,
-        DeadCanStillPlayMessage::on_any_death
-    ]}
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 use crate::game::{
-    abilities_component::Abilities, components::insider_group::InsiderGroups, event::EventData, player::PlayerReference
+    event::EventData, player::PlayerReference
 };
 
 #[must_use = "Event must be invoked"]
@@ -15,11 +15,4 @@ impl OnConcealRole{
 impl EventData for OnConcealRole{
     type FoldValue = ();
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {
-        vec![
-            Abilities::on_conceal_role,
-            InsiderGroups::on_conceal_role
-        ]
-    }
 }
\ No newline at end of file
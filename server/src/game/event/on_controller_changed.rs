@@ -1,6 +1,6 @@
 use crate::game::{
-    controllers::{ControllerID, Controller, Controllers},
-    event::{on_controller_selection_changed::OnControllerSelectionChanged, EventData}
+    controllers::{ControllerID, Controller},
+    event::EventData
 };
 
 pub struct OnControllerChanged{
@@ -11,13 +11,6 @@ pub struct OnControllerChanged{
 impl EventData for OnControllerChanged {
     type FoldValue = ();
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {
-        vec![
-            Controllers::send_controller_to_client,
-            OnControllerSelectionChanged::on_controller_changed
-        ]
-    }
 }
 impl OnControllerChanged{
     pub fn new(id: ControllerID, old: Option<Controller>, new: Option<Controller>)->(Self, ()){
@@ -1,5 +1,5 @@
 use crate::game::{
-    controllers::{ControllerInput, Controllers}, event::EventData,
+    controllers::ControllerInput, event::EventData,
     player::PlayerReference,
 };
 
@@ -16,8 +16,4 @@ impl OnControllerInputReceived{
 impl EventData for OnControllerInputReceived{
     type FoldValue = ();
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        Controllers::on_controller_input_received
-    ]}
 }
\ No newline at end of file
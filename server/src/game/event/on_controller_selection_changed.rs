@@ -1,12 +1,17 @@
 use crate::game::{
-    abilities_component::Abilities, components::mafia::Mafia, controllers::ControllerID, event::{on_controller_changed::OnControllerChanged, AsInvokable as _, EventData, Invokable as _}, Game
+    controllers::ControllerID, event::{on_controller_changed::OnControllerChanged, AsInvokable as _, EventData, Invokable as _}, Game
 };
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 #[must_use = "Event must be invoked"]
 pub struct OnControllerSelectionChanged{
     pub id: ControllerID,
 }
 impl OnControllerSelectionChanged{
+    pub(super) fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(OnControllerSelectionChanged::on_controller_changed));
+    }
+
     pub fn new(id: ControllerID) -> (Self, ()){
         (Self{id}, ())
     }
@@ -22,9 +27,4 @@ impl OnControllerSelectionChanged{
 impl EventData for OnControllerSelectionChanged{
     type FoldValue = ();
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        Mafia::on_controller_selection_changed,
-        Abilities::on_controller_selection_changed
-    ]}
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 use crate::game::{
-    components::{synopsis::SynopsisTracker, win_condition::WinCondition}, event::EventData, player::PlayerReference
+    components::win_condition::WinCondition, event::EventData, player::PlayerReference
 };
 
 #[must_use = "Event must be invoked"]
@@ -16,5 +16,4 @@ impl OnConvert{
 impl EventData for OnConvert{
     type FoldValue = ();
     type Priority = ();
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![SynopsisTracker::on_convert]}
 }
\ No newline at end of file
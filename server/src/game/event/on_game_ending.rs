@@ -1,5 +1,5 @@
 use crate::game::{
-    event::EventData, game_conclusion::GameConclusion, Game
+    event::EventData, game_conclusion::GameConclusion
 };
 
 #[must_use = "Event must be invoked"]
@@ -15,7 +15,4 @@ impl OnGameEnding{
 impl EventData for OnGameEnding{
     type FoldValue = ();
     type Priority = ();
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        Game::on_game_ending,
-    ]}
 }
\ No newline at end of file
@@ -1,8 +1,4 @@
-use crate::game::{
-    components::{
-        cult::Cult, enfranchise::EnfranchiseComponent, forfeit_vote::ForfeitNominationVote, mafia::Mafia
-    }, event::EventData, modifiers::ModifierSettings,
-};
+use crate::game::event::EventData;
 
 #[must_use = "Event must be invoked"]
 pub struct OnGameStart;
@@ -12,11 +8,4 @@ impl OnGameStart{
 impl EventData for OnGameStart{
     type FoldValue = ();
     type Priority = ();
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        ModifierSettings::on_game_start,
-        Mafia::on_game_start,
-        Cult::on_game_start,
-        EnfranchiseComponent::on_game_start,
-        ForfeitNominationVote::on_game_start,
-    ]}
 }
\ No newline at end of file
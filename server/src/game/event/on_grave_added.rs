@@ -1,6 +1,6 @@
 use crate::game::{
-    abilities_component::Abilities, components::graves::{grave_reference::GraveReference, Graves},
-    event::EventData, modifiers::ModifierSettings
+    components::graves::grave_reference::GraveReference,
+    event::EventData
 };
 
 #[must_use = "Event must be invoked"]
@@ -16,10 +16,4 @@ impl EventData for OnGraveAdded{
     type FoldValue = ();
 
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        Abilities::on_grave_added,
-        ModifierSettings::on_grave_added,
-        Graves::on_grave_added
-    ]}
 }
\ No newline at end of file
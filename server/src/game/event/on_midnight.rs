@@ -1,5 +1,6 @@
 use crate::event_priority;
 use crate::game::prelude::*;
+use crate::game::components::night_trace::{NightTrace, NightTraceEvent, NightTraceRecorder, NightTraceSnapshot};
 use super::EventData;
 
 ///runs before all players' night actions
@@ -41,27 +42,13 @@ impl OnMidnight{
 impl EventData for OnMidnight {
     type FoldValue = OnMidnightFold;
     type Priority = OnMidnightPriority;
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {
-        vec![
-            Detained::on_midnight,
-            Poison::on_midnight,
-            PuppeteerMarionette::on_midnight,
-            MafiaRecruits::on_midnight,
-            ModifierSettings::on_midnight,
-            Mafia::on_midnight,
-            PlayerReference::on_midnight,
-            Abilities::on_midnight,
-            FragileVestsComponent::on_midnight,
-            Guard::on_midnight,
-        ]
-    }
 }
 
 #[derive(Default, Clone, Debug)]
 pub struct OnMidnightFold {
     player_data: Vec<PlayerMidnightVariables>,
     visits: Visits,
+    trace: Option<NightTraceRecorder>,
 }
 
 impl OnMidnightFold {
@@ -70,22 +57,55 @@ impl OnMidnightFold {
             player_data: PlayerReference::all_players(game)
                 .map(|player_ref| PlayerMidnightVariables::new(game, player_ref))
                 .collect(),
-            visits: Visits::default()
+            visits: Visits::default(),
+            trace: NightTrace::recorder(game),
         }
     }
 
     pub fn get(&self, player_ref: PlayerReference) -> &PlayerMidnightVariables {
+        debug_assert!((player_ref.index() as usize) < self.player_data.len(), "player {} doesn't exist", player_ref.index());
         unsafe {
             self.player_data.get_unchecked(player_ref.index() as usize)
         }
     }
 
     pub fn get_mut(&mut self, player_ref: PlayerReference) -> &mut PlayerMidnightVariables {
+        debug_assert!((player_ref.index() as usize) < self.player_data.len(), "player {} doesn't exist", player_ref.index());
         unsafe {
             self.player_data.get_unchecked_mut(player_ref.index() as usize)
         }
     }
 
+    pub fn trace_mut(&mut self)->Option<&mut NightTraceRecorder>{
+        self.trace.as_mut()
+    }
+    pub fn take_trace(&mut self)->Option<NightTraceRecorder>{
+        self.trace.take()
+    }
+    /// Does nothing unless the night is being traced, so the event is only built when it's needed
+    pub fn trace_event(&mut self, event: impl FnOnce(&Self)->NightTraceEvent){
+        if self.trace.is_none() {return}
+        let event = event(self);
+        if let Some(trace) = &mut self.trace {
+            trace.push_event(event);
+        }
+    }
+    pub fn trace_snapshot(&self)->Option<NightTraceSnapshot>{
+        let trace = self.trace.as_ref()?;
+        Some(NightTraceSnapshot {
+            player_data: self.player_data.clone(),
+            visit_ledger_len: self.visits.ledger_len(),
+            event_count: trace.event_count(),
+        })
+    }
+    /// Records that the ability acted if it changed anything since the snapshot was taken
+    pub fn trace_ability(&mut self, ability: AbilityID, before: NightTraceSnapshot){
+        if before.player_data == self.player_data && before.visit_ledger_len == self.visits.ledger_len() {return}
+        if let Some(trace) = &mut self.trace {
+            trace.insert_event(before.event_count, NightTraceEvent::AbilityActed { ability });
+        }
+    }
+
     pub fn visits(&self)->&Visits{
         &self.visits
     }
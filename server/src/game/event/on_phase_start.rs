@@ -1,10 +1,5 @@
 use crate::game::{
-    abilities_component::Abilities,
-    components::{
-        ascend::Ascend, blocked::BlockedComponent, call_witness::CallWitness, detained::Detained, fast_forward::FastForwardComponent, forfeit_vote::ForfeitNominationVote, silenced::Silenced, verdicts_today::VerdictsToday
-    },
-    controllers::Controllers, event::EventData, modifiers::ModifierSettings, phase::PhaseState,
-    Game
+    event::EventData, phase::PhaseState
 };
 
 
@@ -20,19 +15,4 @@ impl OnPhaseStart{
 impl EventData for OnPhaseStart{
     type FoldValue = ();
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        BlockedComponent::on_phase_start,
-        Abilities::on_phase_start,
-        ForfeitNominationVote::on_phase_start,
-        Detained::on_phase_start,
-        VerdictsToday::on_phase_start,
-        Controllers::on_phase_start,
-        ModifierSettings::on_phase_start,
-        Silenced::on_phase_start,   //silenced needs to go before call witness, I could do priority but erm
-        CallWitness::on_phase_start,    //must go after silenced
-        Game::on_phase_start,
-        FastForwardComponent::on_phase_start,
-        Ascend::on_phase_start,
-    ]}
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::game::{abilities_component::Abilities, components::mafia::Mafia, event::{on_midnight::OnMidnightFold, EventData}, player::PlayerReference};
+use crate::game::{event::{on_midnight::OnMidnightFold, EventData}, player::PlayerReference};
 
 pub struct OnPlayerPossessed{
     pub possessed: PlayerReference,
@@ -12,9 +12,4 @@ impl OnPlayerPossessed{
 impl EventData for OnPlayerPossessed {
     type FoldValue = OnMidnightFold;
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        Abilities::on_player_possessed,
-        Mafia::on_player_possessed
-    ]}
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 use crate::game::{ 
-    abilities_component::Abilities, components::{blocked::BlockedComponent, mafia::Mafia}, event::EventData, player::PlayerReference,
+    event::EventData, player::PlayerReference,
 };
 
 use super::on_midnight::OnMidnightFold;
@@ -17,10 +17,4 @@ impl OnPlayerRoleblocked{
 impl EventData for OnPlayerRoleblocked{
     type FoldValue = OnMidnightFold;
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        Abilities::on_player_roleblocked,
-        Mafia::on_player_roleblocked,
-        BlockedComponent::on_player_roleblocked,
-    ]}
 }
\ No newline at end of file
@@ -1,7 +1,5 @@
 use crate::game::{
-    abilities_component::Abilities, components::{
-        cult::Cult, insider_group::InsiderGroupID, mafia::Mafia, mafia_recruits::MafiaRecruits, puppeteer_marionette::PuppeteerMarionette
-    }, player::PlayerReference,
+    components::insider_group::InsiderGroupID, player::PlayerReference,
 };
 use super::EventData;
 
@@ -26,14 +24,4 @@ impl OnRemoveInsider {
 impl EventData for OnRemoveInsider {
     type FoldValue = ();
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {
-        vec![
-            Abilities::on_remove_insider,
-            PuppeteerMarionette::on_remove_insider,
-            Cult::on_remove_insider,
-            Mafia::on_remove_insider,
-            MafiaRecruits::on_remove_insider,
-        ]
-    }
 }
\ No newline at end of file
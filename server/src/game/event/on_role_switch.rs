@@ -1,5 +1,4 @@
 use crate::game::{
-    abilities_component::Abilities, components::{cult::Cult, mafia::Mafia, role_reveal::RevealedPlayersComponent, synopsis::SynopsisTracker},
     event::EventData, player::PlayerReference, role::RoleState,
 };
 
@@ -17,12 +16,4 @@ impl OnRoleSwitch{
 impl EventData for OnRoleSwitch{
     type FoldValue = ();
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        RevealedPlayersComponent::on_role_switch,
-        Cult::on_role_switch,
-        Mafia::on_role_switch,
-        SynopsisTracker::on_role_switch,
-        Abilities::on_role_switch,
-    ]}
 }
\ No newline at end of file
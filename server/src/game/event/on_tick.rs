@@ -1,6 +1,4 @@
-use crate::game::{
-    components::{ascend::Ascend, hide_votes_message::HideVotesMessage}, controllers::Controllers, event::EventData,
-};
+use crate::game::event::EventData;
 
 pub struct OnTick;
 
@@ -12,10 +10,4 @@ impl OnTick{
 impl EventData for OnTick{
     type FoldValue = ();
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        Controllers::on_tick,
-        Ascend::on_tick,
-        HideVotesMessage::on_tick
-    ]}
 }
\ No newline at end of file
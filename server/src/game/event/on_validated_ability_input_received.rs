@@ -1,8 +1,4 @@
 use crate::game::{
-    abilities_component::Abilities, chat::ChatComponent,
-    components::{
-        call_witness::CallWitness, forward_messages::ForwardMessages, nomination_controller::NominationController,
-    },
     controllers::ControllerInput, event::EventData, player::PlayerReference,
 };
 
@@ -19,14 +15,4 @@ impl OnValidatedControllerInputReceived{
 impl EventData for OnValidatedControllerInputReceived{
     type FoldValue = ();
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {
-        vec![
-            Abilities::on_validated_ability_input_received,
-            ForwardMessages::on_validated_ability_input_received,
-            NominationController::on_validated_ability_input_received,
-            ChatComponent::on_validated_ability_input_received,
-            CallWitness::on_validated_ability_input_received
-        ]
-    }
 }
\ No newline at end of file
@@ -13,10 +13,4 @@ impl OnVisitWardblocked{
 impl EventData for OnVisitWardblocked{
     type FoldValue = OnMidnightFold;
     type Priority = ();
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {vec![
-        Abilities::on_visit_wardblocked,
-        Mafia::on_visit_wardblocked,
-        BlockedComponent::on_visit_wardblocked,
-    ]}
 }
\ No newline at end of file
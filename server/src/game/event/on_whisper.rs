@@ -1,4 +1,4 @@
-use crate::{event_priority, game::{abilities_component::Abilities, chat::ChatComponent, modifiers::ModifierSettings, player::PlayerReference}};
+use crate::{event_priority, game::player::PlayerReference};
 use super::EventData;
 
 #[derive(Clone)]
@@ -32,12 +32,4 @@ impl OnWhisper {
 impl EventData for OnWhisper {
     type FoldValue = WhisperFold;
     type Priority = WhisperPriority;
-
-    fn listeners() -> Vec<super::EventListenerFunction<Self>> {
-        vec![
-            ChatComponent::on_whisper,
-            ModifierSettings::on_whisper,
-            Abilities::on_whisper,
-        ]
-    }
 }
\ No newline at end of file
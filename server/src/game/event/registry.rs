@@ -0,0 +1,180 @@
+use std::{any::{Any, TypeId}, collections::HashMap, sync::Arc};
+
+#[cfg(any(test, feature = "test-kit"))]
+use rand::{rngs::SmallRng, seq::SliceRandom, SeedableRng};
+
+use crate::game::{
+    abilities_component::Abilities,
+    chat::ChatComponent,
+    components::{
+        ascend::Ascend, blocked::BlockedComponent, call_witness::CallWitness, cult::Cult,
+        dead_can_still_play_message::DeadCanStillPlayMessage, detained::Detained,
+        enfranchise::EnfranchiseComponent, fast_forward::FastForwardComponent,
+        forfeit_vote::ForfeitNominationVote, forward_messages::ForwardMessages,
+        fragile_vest::FragileVestsComponent, graves::Graves, guard::Guard,
+        hide_votes_message::HideVotesMessage, inactivity::Inactivity, insider_group::InsiderGroups,
+        mafia::Mafia, mafia_recruits::MafiaRecruits, night_trace::NightTrace,
+        nomination_controller::NominationController, poison::Poison,
+        puppeteer_marionette::PuppeteerMarionette, role::RoleComponent,
+        role_reveal::RevealedPlayersComponent, silenced::Silenced, synopsis::SynopsisTracker,
+        verdicts_today::VerdictsToday, vote_history::VoteHistory
+    },
+    controllers::Controllers,
+    player::PlayerReference,
+    settings::Settings,
+    Game
+};
+
+use super::{on_controller_selection_changed::OnControllerSelectionChanged, EventData, EventListenerFunction};
+
+/// Every listener of every event, subscribed when the game is created.
+/// Listeners of one event run in order of their [`ListenerOrder`], then in the order they subscribed.
+#[derive(Default)]
+pub struct EventRegistry {
+    listeners: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+    /// Events whose listeners run in a random order, see [`EventRegistry::shuffle_listeners`]
+    #[cfg(any(test, feature = "test-kit"))]
+    shuffled: HashMap<TypeId, SmallRng>,
+}
+
+/// Where a listener runs compared to the other listeners of the same event and priority
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ListenerOrder {
+    First,
+    Early,
+    Normal,
+    Late,
+    Last,
+}
+
+pub struct EventListener<E: EventData> {
+    pub name: &'static str,
+    pub order: ListenerOrder,
+    pub function: EventListenerFunction<E>,
+}
+impl<E: EventData> Clone for EventListener<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<E: EventData> Copy for EventListener<E> {}
+
+impl<E: EventData> EventListener<E> {
+    pub fn new(name: &'static str, function: EventListenerFunction<E>) -> Self {
+        Self { name, order: ListenerOrder::Normal, function }
+    }
+    pub fn order(mut self, order: ListenerOrder) -> Self {
+        self.order = order;
+        self
+    }
+}
+
+/// Names the listener after the function it calls
+#[macro_export]
+macro_rules! event_listener {
+    ($owner:ident :: $function:ident) => {
+        $crate::game::event::registry::EventListener::new(
+            concat!(stringify!($owner), "::", stringify!($function)),
+            $owner::$function
+        )
+    };
+}
+
+impl EventRegistry {
+    /// The listeners every game with these settings starts with
+    pub fn new(settings: &Settings) -> Self {
+        let mut registry = Self::default();
+
+        NightTrace::subscribe_to_events(&mut registry);
+        BlockedComponent::subscribe_to_events(&mut registry);
+        RoleComponent::subscribe_to_events(&mut registry);
+        Abilities::subscribe_to_events(&mut registry);
+        ForfeitNominationVote::subscribe_to_events(&mut registry);
+        Detained::subscribe_to_events(&mut registry);
+        VerdictsToday::subscribe_to_events(&mut registry);
+        Controllers::subscribe_to_events(&mut registry);
+        Poison::subscribe_to_events(&mut registry);
+        PuppeteerMarionette::subscribe_to_events(&mut registry);
+        MafiaRecruits::subscribe_to_events(&mut registry);
+        settings.modifiers.subscribe_to_events(&mut registry);
+        Mafia::subscribe_to_events(&mut registry);
+        PlayerReference::subscribe_to_events(&mut registry);
+        FragileVestsComponent::subscribe_to_events(&mut registry);
+        Guard::subscribe_to_events(&mut registry);
+        RevealedPlayersComponent::subscribe_to_events(&mut registry);
+        Cult::subscribe_to_events(&mut registry);
+        EnfranchiseComponent::subscribe_to_events(&mut registry);
+        InsiderGroups::subscribe_to_events(&mut registry);
+        Graves::subscribe_to_events(&mut registry);
+        DeadCanStillPlayMessage::subscribe_to_events(&mut registry);
+        SynopsisTracker::subscribe_to_events(&mut registry);
+        OnControllerSelectionChanged::subscribe_to_events(&mut registry);
+        ForwardMessages::subscribe_to_events(&mut registry);
+        NominationController::subscribe_to_events(&mut registry);
+        ChatComponent::subscribe_to_events(&mut registry);
+        // Silenced has to start the phase before CallWitness, so a silenced defendant's witnesses are chosen before they're announced.
+        // Both are in the Normal order, moving either out of it would also move it past Controllers or Game.
+        Silenced::subscribe_to_events(&mut registry);
+        CallWitness::subscribe_to_events(&mut registry);
+        Game::subscribe_to_events(&mut registry);
+        FastForwardComponent::subscribe_to_events(&mut registry);
+        Ascend::subscribe_to_events(&mut registry);
+        HideVotesMessage::subscribe_to_events(&mut registry);
+        VoteHistory::subscribe_to_events(&mut registry);
+        Inactivity::subscribe_to_events(&mut registry);
+
+        registry
+    }
+
+    pub fn subscribe<E: EventData>(&mut self, listener: EventListener<E>) {
+        let listeners = self.listeners
+            .entry(TypeId::of::<E>())
+            .or_insert_with(|| Box::new(Arc::new(Vec::<EventListener<E>>::new())))
+            .downcast_mut::<Arc<Vec<EventListener<E>>>>();
+
+        let Some(listeners) = listeners else {return};
+        let listeners = Arc::make_mut(listeners);
+        let index = listeners.iter()
+            .position(|other| other.order > listener.order)
+            .unwrap_or(listeners.len());
+        listeners.insert(index, listener);
+    }
+
+    /// In the order they run
+    pub fn listeners<E: EventData>(&self) -> Arc<Vec<EventListener<E>>> {
+        self.listeners.get(&TypeId::of::<E>())
+            .and_then(|listeners| listeners.downcast_ref::<Arc<Vec<EventListener<E>>>>())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn listener_names<E: EventData>(&self) -> Vec<&'static str> {
+        self.listeners::<E>().iter().map(|listener| listener.name).collect()
+    }
+
+    /// Only for tests, behind the `test-kit` feature.
+    /// From now on, listeners of this event with the same [`ListenerOrder`] run in a random order for every priority.
+    /// Anything that iterates on behalf of this event, like abilities at midnight, is shuffled too.
+    /// If the outcome changes, something depends on an order nobody chose.
+    #[cfg(any(test, feature = "test-kit"))]
+    pub fn shuffle_listeners<E: EventData>(&mut self, seed: u64) {
+        self.shuffled.insert(TypeId::of::<E>(), SmallRng::seed_from_u64(seed));
+    }
+    #[cfg(any(test, feature = "test-kit"))]
+    pub fn is_shuffled<E: EventData>(&self) -> bool {
+        self.shuffled.contains_key(&TypeId::of::<E>())
+    }
+    #[cfg(any(test, feature = "test-kit"))]
+    /// Does nothing unless [`EventRegistry::shuffle_listeners`] was called for this event
+    pub fn shuffle<E: EventData, T>(&mut self, items: &mut [T]) {
+        if let Some(rng) = self.shuffled.get_mut(&TypeId::of::<E>()) {
+            items.shuffle(rng);
+        }
+    }
+    #[cfg(any(test, feature = "test-kit"))]
+    pub(super) fn shuffle_within_order<E: EventData>(&mut self, listeners: &mut [EventListener<E>]) {
+        for same_order in listeners.chunk_by_mut(|a, b| a.order == b.order) {
+            self.shuffle::<E, _>(same_order);
+        }
+    }
+}
@@ -10,6 +10,9 @@ pub struct GameClient{
     #[serde(rename = "clientType")]
     pub client_location: GameClientLocation,
     pub host: bool,
+    /// Muted in the lobby chat. Kept through the game so the mute still holds back in the lobby.
+    #[serde(skip)]
+    pub muted: bool,
 
     #[serde(skip)]
     pub last_message_times: VecDeque<Instant>,
@@ -25,6 +28,15 @@ impl GameClient {
         GameClient{
             client_location: GameClientLocation::Spectator(pointer),
             host,
+            muted: false,
+            last_message_times: VecDeque::new(),
+        }
+    }
+    pub fn new_player(player: PlayerReference, host: bool)->Self{
+        GameClient{
+            client_location: GameClientLocation::Player(player),
+            host,
+            muted: false,
             last_message_times: VecDeque::new(),
         }
     }
@@ -7,17 +7,28 @@ use super::{
     chat::{ChatGroup, ChatMessageVariant},
     components::synopsis::SynopsisTracker,
     phase::{PhaseState, PhaseStateMachine},
+    spectator::spectator_pointer::SpectatorPointer,
     Game, GameOverReason
 };
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 //Event listerner functions for game defined here
 impl Game{
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(Game::on_game_ending));
+        registry.subscribe(event_listener!(Game::on_phase_start));
+    }
+
     pub fn on_phase_start(&mut self, _event: &OnPhaseStart, _fold: &mut (), _priority: ()){
+        let released = self.spectator_feed.on_phase_start();
+        self.send_spectator_feed_entries(released);
+
         self.send_packet_to_all(ToClientPacket::Phase { 
             phase: self.current_phase().clone(),
             day_number: self.phase_machine.day_number,
         });
         self.send_packet_to_all(ToClientPacket::PhaseTimeLeft{ seconds_left: self.phase_machine.time_remaining.map(|o|o.as_secs().try_into().expect("Phase time should be below 18 hours")) });
+        SpectatorPointer::send_omniscient_role_labels(self);
     }
     pub fn on_game_ending(&mut self, event: &OnGameEnding, _fold: &mut (), _priority: ()){
         let synopsis = SynopsisTracker::get(self, event.conclusion);
@@ -25,6 +36,7 @@ impl Game{
         PhaseStateMachine::next_phase(self, Some(PhaseState::Recess));
         self.add_message_to_chat_group(ChatGroup::All, ChatMessageVariant::GameOver { synopsis });
         self.send_packet_to_all(ToClientPacket::GameOver{ reason: GameOverReason::Draw });
+        self.end_spectator_feed_delay();
         
         self.ticking = false;
     }
@@ -2,6 +2,7 @@
 
 pub mod game_client;
 pub mod phase;
+pub mod phase_schedule;
 pub mod player;
 pub mod chat;
 pub mod role;
@@ -51,7 +52,15 @@ use components::silenced::Silenced;
 use components::synopsis::SynopsisTracker;
 use components::tags::Tags;
 use components::verdicts_today::VerdictsToday;
+use components::nomination_revote::NominationRevote;
+use components::pause::Pause;
+use components::moderation::Moderation;
+use components::inactivity::Inactivity;
+use components::vote_history::VoteHistory;
+use components::night_trace::NightTrace;
+use event::registry::EventRegistry;
 use rand::rngs::SmallRng;
+use rand::seq::IteratorRandom as _;
 use serde::Serialize;
 use crate::client_connection::ClientConnection;
 use crate::game::chat::ChatComponent;
@@ -61,15 +70,18 @@ use crate::game::components::win_condition::WinConditionComponent;
 use crate::game::game_client::GameClient;
 use crate::game::game_client::GameClientLocation;
 use crate::game::modifiers::hidden_nomination_votes::HiddenNominationVotes;
+use crate::game::modifiers::plurality_nominations::{PluralityNominations, PluralityTieBreak};
 use crate::game::role_list_generation::OutlineAssignment;
+use crate::lobby::lobby_chat::LobbyChat;
 use crate::room::RoomClientID;
+use crate::room::room_access::RoomAccess;
 use crate::room::name_validation;
 use crate::packet::HostDataPacketGameClient;
 use crate::packet::RejectJoinReason;
 use crate::packet::ToClientPacket;
 use crate::vec_map::VecMap;
 use crate::vec_set::VecSet;
-use chat::{ChatMessageVariant, ChatGroup, ChatMessage};
+use chat::{ChatMessageVariant, ChatGroup, ChatMessage, ChatMessageIndex};
 use player::PlayerReference;
 use player::Player;
 use phase::PhaseStateMachine;
@@ -81,6 +93,7 @@ use self::components::{
 use self::game_conclusion::GameConclusion;
 use self::phase::PhaseState;
 use self::spectator::{
+    spectator_feed::{SpectatorFeed, SpectatorFeedEntry},
     spectator_pointer::{
         SpectatorIndex, SpectatorPointer
     },
@@ -92,11 +105,14 @@ use self::verdict::Verdict;
 
 pub struct Game {
     room_name: String,
+    lobby_chat: LobbyChat,
+    access: RoomAccess,
     clients: VecMap<RoomClientID, GameClient>,
     pub settings : Settings,
 
     pub spectators: Vec<Spectator>,
     pub spectator_chat_messages: Vec<ChatMessageVariant>,
+    pub spectator_feed: SpectatorFeed,
 
     /// indexed by role outline reference
     pub assignments: Assignments,
@@ -123,6 +139,13 @@ pub struct Game {
     pub puppeteer_marionette: PuppeteerMarionette,
     pub mafia_recruits: MafiaRecruits,
     pub verdicts_today: VerdictsToday,
+    pub nomination_revote: NominationRevote,
+    pub pause: Pause,
+    pub moderation: Moderation,
+    pub inactivity: Inactivity,
+    pub vote_history: VoteHistory,
+    pub night_trace: NightTrace,
+    pub event_registry: EventRegistry,
     pub blocked: BlockedComponent,
     pub pitchfork_item: PitchforkItemComponent,
     pub poison: Poison,
@@ -148,7 +171,9 @@ pub enum RejectStartReason {
     RoleListTooSmall,
     RoleListCannotCreateRoles,
     ZeroTimeGame,
-    PlayerDisconnected
+    PlayerDisconnected,
+    InvalidPhaseSchedule,
+    InvalidPhaseTimes
 }
 
 #[derive(Serialize, Debug, Clone, Copy)]
@@ -193,15 +218,20 @@ impl Game {
             if !player.alive(self) { continue }
 
             let Some(PlayerListSelection(voted_players)) = ControllerID::Nominate { player }.get_player_list_selection(self) else {continue};
-            let Some(&voted_player) = voted_players.first() else { continue };
-            
+            let voted_players: VecSet<PlayerReference> = if self.modifier_settings().is_enabled(ModifierID::ApprovalNominations) {
+                voted_players.iter().copied().collect()
+            } else {
+                voted_players.first().copied().into_iter().collect()
+            };
 
             let voting_power: u8 = EnfranchiseComponent::voting_power(self, player);
 
-            if let Some(num_votes) = voted_player_votes.get_mut(&voted_player) {
-                *num_votes = num_votes.saturating_add(voting_power);
-            } else {
-                voted_player_votes.insert(voted_player, voting_power);
+            for voted_player in voted_players {
+                if let Some(num_votes) = voted_player_votes.get_mut(&voted_player) {
+                    *num_votes = num_votes.saturating_add(voting_power);
+                } else {
+                    voted_player_votes.insert(voted_player, voting_power);
+                }
             }
         }
 
@@ -210,7 +240,7 @@ impl Game {
     /// Returns the player who is meant to be put on trial
     /// None if its not nomination
     /// None if nobody has enough votes
-    /// None if there is a tie
+    /// None if there is a tie, unless plurality nominations break it randomly
     pub fn count_nomination_and_start_trial(&mut self, start_trial_instantly: bool)->Option<PlayerReference>{
 
         let &PhaseState::Nomination { trials_left, .. } = self.current_phase() else {return None};
@@ -218,16 +248,20 @@ impl Game {
         let voted_player_votes = self.create_nominated_player_map();
         self.send_player_votes();
 
-        let mut voted_player = None;
+        let plurality_tie_break = PluralityNominations::tie_break(self);
+        // Plurality is only decided once nomination ends
+        if plurality_tie_break.is_some() && start_trial_instantly {return None}
 
-        if let Some(maximum_votes) = voted_player_votes.values().max() && self.nomination_votes_is_enough(*maximum_votes){
-            let max_votes_players: VecSet<PlayerReference> = voted_player_votes.iter()
-                .filter(|(_, votes)| **votes == *maximum_votes)
-                .map(|(player, _)| *player)
-                .collect();
+        let mut voted_player = None;
 
+        let max_votes_players = Self::most_nominated_players(&voted_player_votes);
+        if let Some(maximum_votes) = voted_player_votes.values().max() && 
+            (plurality_tie_break.is_some() || self.nomination_votes_is_enough(*maximum_votes))
+        {
             if max_votes_players.count() == 1 {
                 voted_player = max_votes_players.iter().next().copied();
+            } else if plurality_tie_break == Some(PluralityTieBreak::Random) {
+                voted_player = max_votes_players.iter().choose(&mut self.rng).copied();
             }
         }
         
@@ -242,6 +276,28 @@ impl Game {
     }
 
     
+    /// Players tied for the most nomination votes. Players with no votes are never included.
+    fn most_nominated_players(voted_player_votes: &VecMap<PlayerReference, u8>)->VecSet<PlayerReference>{
+        let Some(maximum_votes) = voted_player_votes.values().max().copied() else {return VecSet::new()};
+        if maximum_votes == 0 {return VecSet::new()}
+
+        voted_player_votes.iter()
+            .filter(|(_, votes)| **votes == maximum_votes)
+            .map(|(player, _)| *player)
+            .collect()
+    }
+    /// Starts a plurality revote between the tied players if the tie break calls for one.
+    /// Returns true if the revote was started.
+    pub fn start_nomination_revote(&mut self)->bool{
+        if PluralityNominations::tie_break(self) != Some(PluralityTieBreak::Revote) {return false}
+
+        let tied_players = Self::most_nominated_players(&self.create_nominated_player_map());
+        if tied_players.count() < 2 {return false}
+
+        NominationRevote::start(self, tied_players);
+        true
+    }
+
     pub fn nomination_votes_is_enough(&self, votes: u8)->bool{
         votes >= self.nomination_votes_required()
     }
@@ -270,6 +326,13 @@ impl Game {
         }
     }
 
+    pub fn access(&self) -> &RoomAccess {
+        &self.access
+    }
+    pub fn access_mut(&mut self) -> &mut RoomAccess {
+        &mut self.access
+    }
+
     pub fn modifier_settings(&self) -> &modifiers::ModifierSettings {
         &self.settings.modifiers
     }
@@ -286,6 +349,10 @@ impl Game {
         self.phase_machine.day_number
     }
 
+    pub fn phase_schedule(&self) -> &phase_schedule::PhaseSchedule {
+        &self.phase_machine.schedule
+    }
+
     pub fn add_message_to_chat_group(&mut self, group: ChatGroup, variant: ChatMessageVariant){
         let message = ChatMessage::new_non_private(variant.clone(), group);
 
@@ -305,10 +372,36 @@ impl Game {
     }
     pub fn add_chat_message_to_spectators(&mut self, message: ChatMessageVariant){
         let new_idx = self.spectator_chat_messages.len();
+        self.spectator_chat_messages.push(message);
+
+        if self.spectator_feed.is_delayed() {
+            self.spectator_feed.push(SpectatorFeedEntry::ChatMessage(new_idx));
+        } else {
+            self.queue_chat_message_for_spectators(new_idx);
+        }
+    }
+    fn queue_chat_message_for_spectators(&mut self, idx: ChatMessageIndex){
+        let Some(message) = self.spectator_chat_messages.get(idx).cloned() else { return };
         for spectator in self.spectators.iter_mut(){
-            spectator.queued_chat_messages.push_back((new_idx, message.clone()));
+            spectator.queued_chat_messages.push_back((idx, message.clone()));
+        }
+    }
+    pub fn send_spectator_feed_entries(&mut self, entries: Vec<SpectatorFeedEntry>){
+        for entry in entries {
+            match entry {
+                SpectatorFeedEntry::Packet(packet) => {
+                    for spectator in self.spectators.iter(){
+                        spectator.send_packet(packet.clone());
+                    }
+                },
+                SpectatorFeedEntry::ChatMessage(idx) => self.queue_chat_message_for_spectators(idx),
+            }
         }
-        self.spectator_chat_messages.push(message);
+    }
+    /// Once the game is over there is nothing left to hide from spectators
+    pub fn end_spectator_feed_delay(&mut self){
+        let entries = self.spectator_feed.release_all();
+        self.send_spectator_feed_entries(entries);
     }
     pub fn join_spectator(&mut self, params: SpectatorInitializeParameters) -> Result<SpectatorPointer, RejectJoinReason> {
         let spectator_index = SpectatorIndex::try_from(self.spectators.len()).map_err(|_|RejectJoinReason::RoomFull)?;
@@ -322,13 +415,73 @@ impl Game {
             self.spectators.remove(i as usize);
         }
     }
+    /// Gives a player's seat to a connected spectator.
+    /// If the player's old client is still connected it becomes a spectator, otherwise it's removed.
+    fn hand_over_seat(&mut self, player: PlayerReference, replacement_id: RoomClientID) -> bool {
+        let Some(replacement) = self.clients.get(&replacement_id) else {return false};
+        let GameClientLocation::Spectator(spectator) = replacement.client_location else {return false};
+        let (replacement_host, replacement_muted) = (replacement.host, replacement.muted);
+        let ClientConnection::Connected(sender) = spectator.connection(self) else {return false};
+
+        let old_client = self.clients.iter()
+            .find(|(_, client)| matches!(client.client_location, GameClientLocation::Player(p) if p == player))
+            .map(|(id, client)| (*id, client.host, client.muted));
+
+        self.remove_spectator_client(replacement_id, spectator);
+
+        let mut old_client_spectator = None;
+        if let Some((old_id, old_host, old_muted)) = old_client {
+            self.clients.remove(&old_id);
+
+            if player.is_connected(self) && let Ok(new_spectator) = self.join_spectator(SpectatorInitializeParameters {
+                connection: player.connection(self).clone(),
+                host: old_host,
+            }) {
+                self.clients.insert(old_id, GameClient { muted: old_muted, ..GameClient::new_spectator(new_spectator, old_host) });
+                old_client_spectator = Some(new_spectator);
+            }
+        }
+
+        self.clients.insert(replacement_id, GameClient { muted: replacement_muted, ..GameClient::new_player(player, replacement_host) });
+        player.connect(self, sender);
+        if let Some(new_spectator) = old_client_spectator {
+            new_spectator.send_join_game_data(self);
+        }
+
+        self.ensure_host_exists(None);
+        self.send_players();
+        self.resend_host_data_to_all_hosts();
+        true
+    }
+    fn remove_spectator_client(&mut self, room_client_id: RoomClientID, spectator: SpectatorPointer){
+        self.clients.remove(&room_client_id);
+
+        // Shift every other spectator down one index
+        for client in self.clients.iter_mut() {
+            if 
+                let GameClientLocation::Spectator(other) = &mut client.1.client_location && 
+                other.index() > spectator.index()
+            {
+                *other = SpectatorPointer::new(other.index().saturating_sub(1));
+            }
+        }
+
+        self.remove_spectator(spectator.index());
+    }
 
-    pub fn send_packet_to_all(&self, packet: ToClientPacket){
+    pub fn send_packet_to_all(&mut self, packet: ToClientPacket){
         for player_ref in PlayerReference::all_players(self){
             player_ref.send_packet(self, packet.clone());
         }
-        for spectator in self.spectators.iter(){
-            spectator.send_packet(packet.clone());
+        self.send_packet_to_spectators(packet);
+    }
+    pub fn send_packet_to_spectators(&mut self, packet: ToClientPacket){
+        if self.spectator_feed.is_delayed() {
+            self.spectator_feed.push(SpectatorFeedEntry::Packet(packet));
+        } else {
+            for spectator in self.spectators.iter(){
+                spectator.send_packet(packet.clone());
+            }
         }
     }
     
@@ -416,7 +569,8 @@ impl Game {
                         self.create_nominated_player_map()
                     }else{
                         VecMap::new()
-                    }
+                    },
+                revote_candidates: NominationRevote::candidates(self).cloned()
             }
         );
     }
@@ -429,12 +583,12 @@ impl Game {
 
         other_players.remove(player_ref.index() as usize);
         
-        let new_name: String = name_validation::sanitize_name(name, &other_players);
+        let new_name: String = name_validation::sanitize_name(name, &other_players, &mut self.rng);
 
         player_ref.set_name(self, new_name);
     }
     
-    fn send_to_all(&self, packet: ToClientPacket) {
+    fn send_to_all(&mut self, packet: ToClientPacket) {
         self.send_packet_to_all(packet.clone())
     }
     
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ModifierStateImpl, ModifierID};
+
+/// Players can nominate any number of players, and each nomination counts fully
+#[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+pub struct ApprovalNominations;
+
+/*
+    There is modifier specific code in the NominationController and create_nominated_player_map() function
+*/
+impl From<&ApprovalNominations> for ModifierID{
+    fn from(_: &ApprovalNominations) -> Self {
+        ModifierID::ApprovalNominations
+    }
+}
+impl ModifierStateImpl for ApprovalNominations{}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ModifierStateImpl, ModifierID};
+
+/// The game goes from the role reveal in Briefing straight to Discussion 1, with trials, before the first night
+#[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+pub struct DayStart;
+
+/*
+    There is modifier specific code in Settings::game_phase_schedule
+*/
+impl From<&DayStart> for ModifierID{
+    fn from(_: &DayStart) -> Self {
+        ModifierID::DayStart
+    }
+}
+
+impl ModifierStateImpl for DayStart{}
@@ -18,6 +18,13 @@ pub mod hidden_verdict_votes;
 pub mod forfeit_vote;
 pub mod random_player_names;
 pub mod no_majority;
+pub mod plurality_nominations;
+pub mod approval_nominations;
+pub mod vote_lock;
+pub mod no_night_1_attacks;
+pub mod night_1_investigative_only;
+pub mod day_start;
+pub mod night_breakdown;
 
 use crate::{
     game::event::{
@@ -35,9 +42,13 @@ use super::{
     },
     player::PlayerReference, Game
 };
+use crate::game::event::registry::EventRegistry;
 
 pub trait ModifierStateImpl where Self: Clone + Sized + Default + Serialize + for<'de> Deserialize<'de>{
-    fn on_midnight(self, _game: &mut Game, _priority: OnMidnightPriority) {}
+    /// Subscribes the hooks this modifier implements with [`modifier_listener`](crate::modifier_listener).
+    /// Only enabled modifiers are subscribed, when the game is created.
+    fn subscribe_to_events(_registry: &mut EventRegistry) {}
+    fn on_midnight(self, _game: &mut Game, _fold: &mut OnMidnightFold, _priority: OnMidnightPriority) {}
     fn before_phase_end(self, _game: &mut Game, _phase: super::phase::PhaseType) {}
     fn on_phase_start(self, _game: &mut Game, _event: &OnPhaseStart, _fold: &mut (), _priority: ()) {}
     fn on_grave_added(self, _game: &mut Game, _event: &OnGraveAdded, _fold: &mut (), _priority: ()) {}
@@ -49,6 +60,9 @@ pub trait ModifierStateImpl where Self: Clone + Sized + Default + Serialize + fo
 macros::modifiers! {
     obscured_graves: ObscuredGraves,
     skip_day_1: SkipDay1,
+    day_start: DayStart,
+    no_night_1_attacks: NoNight1Attacks,
+    night_1_investigative_only: Night1InvestigativeOnly,
     dead_can_chat: DeadCanChat,
     abstaining: Abstaining,
     no_death_cause: NoDeathCause,
@@ -56,6 +70,8 @@ macros::modifiers! {
     no_due_process: AutoGuilty,
     two_thirds_majority: TwoThirdsMajority,
     no_majority: NoMajority,
+    plurality_nominations: PluralityNominations,
+    approval_nominations: ApprovalNominations,
     no_trial: NoTrialPhases,
     no_whispers: NoWhispers,
     no_night_chat: NoNightChat,
@@ -65,7 +81,9 @@ macros::modifiers! {
     hidden_nomination_votes: HiddenNominationVotes,
     hidden_verdict_votes: HiddenVerdictVotes,
     forfeit_vote: ForfeitNominationVote,
+    vote_lock: VoteLock,
     random_player_names: RandomPlayerNames,
+    night_breakdown: NightBreakdown,
     custom_role_limits: CustomRoleLimits
 }
 
@@ -76,9 +94,19 @@ pub struct ModifierSettings{
 }
 
 impl ModifierSettings{
+    /// Every enabled modifier subscribes its own hooks, in the order they were enabled
+    pub fn subscribe_to_events(&self, registry: &mut EventRegistry){
+        for state in self.modifiers.values() {
+            state.subscribe_to_events(registry);
+        }
+    }
+
     pub fn is_enabled(&self, modifier: ModifierID)->bool{
         self.modifiers.contains(&modifier)
     }
+    pub fn enabled_modifiers(&self)->impl Iterator<Item = ModifierID> + '_{
+        self.modifiers.keys().copied()
+    }
     pub fn get_modifier(&self, modifier: ModifierID)->Option<&ModifierState>{
         self.modifiers.get(&modifier)
     }
@@ -98,39 +126,76 @@ impl ModifierSettings{
             state
         );
     }
-    pub fn on_midnight(game: &mut Game, _event: &OnMidnight, _fold: &mut OnMidnightFold, priority: OnMidnightPriority){
-        for modifier in game.modifier_settings().modifiers.clone(){
-            modifier.1.on_midnight(game, priority);
+}
+
+/// Names the listener after the modifier and hook it calls
+#[macro_export]
+macro_rules! modifier_listener {
+    ($modifier:ident :: $hook:ident) => {
+        $crate::game::event::registry::EventListener::new(
+            concat!(stringify!($modifier), "::", stringify!($hook)),
+            $crate::game::modifiers::listeners::$hook::<$modifier>
+        )
+    };
+}
+
+/// Calls one modifier's hook with its state from the settings
+pub mod listeners {
+    use super::*;
+
+    fn state<M>(game: &Game) -> Option<ModifierState>
+    where for<'a> ModifierID: From<&'a M>, M: ModifierStateImpl
+    {
+        game.modifier_settings().get_modifier(ModifierID::from(&M::default())).cloned()
+    }
+
+    pub fn on_midnight<M>(game: &mut Game, _event: &OnMidnight, fold: &mut OnMidnightFold, priority: OnMidnightPriority)
+    where for<'a> ModifierID: From<&'a M>, M: ModifierStateImpl
+    {
+        if let Some(state) = state::<M>(game) {
+            state.on_midnight(game, fold, priority);
         }
     }
-    pub fn on_grave_added(game: &mut Game, event: &OnGraveAdded, fold: &mut (), priority: ()){
-        for modifier in game.modifier_settings().modifiers.clone(){
-            modifier.1.on_grave_added(game, event, fold, priority);
+    pub fn on_grave_added<M>(game: &mut Game, event: &OnGraveAdded, fold: &mut (), priority: ())
+    where for<'a> ModifierID: From<&'a M>, M: ModifierStateImpl
+    {
+        if let Some(state) = state::<M>(game) {
+            state.on_grave_added(game, event, fold, priority);
         }
     }
-    pub fn on_game_start(game: &mut Game, event: &OnGameStart, fold: &mut (), priority: ()){
-        for modifier in game.modifier_settings().modifiers.clone(){
-            modifier.1.on_game_start(game, event, fold, priority);
+    pub fn on_game_start<M>(game: &mut Game, event: &OnGameStart, fold: &mut (), priority: ())
+    where for<'a> ModifierID: From<&'a M>, M: ModifierStateImpl
+    {
+        if let Some(state) = state::<M>(game) {
+            state.on_game_start(game, event, fold, priority);
         }
     }
-    pub fn before_phase_end(game: &mut Game, event: &BeforePhaseEnd, _fold: &mut (), _priority: ()){
-        for modifier in game.modifier_settings().modifiers.clone(){
-            modifier.1.before_phase_end(game, event.phase);
+    pub fn before_phase_end<M>(game: &mut Game, event: &BeforePhaseEnd, _fold: &mut (), _priority: ())
+    where for<'a> ModifierID: From<&'a M>, M: ModifierStateImpl
+    {
+        if let Some(state) = state::<M>(game) {
+            state.before_phase_end(game, event.phase);
         }
     }
-    pub fn on_phase_start(game: &mut Game, event: &OnPhaseStart, _fold: &mut (), _priority: ()){
-        for modifier in game.modifier_settings().modifiers.clone(){
-            modifier.1.on_phase_start(game, event, _fold, _priority);
+    pub fn on_phase_start<M>(game: &mut Game, event: &OnPhaseStart, fold: &mut (), priority: ())
+    where for<'a> ModifierID: From<&'a M>, M: ModifierStateImpl
+    {
+        if let Some(state) = state::<M>(game) {
+            state.on_phase_start(game, event, fold, priority);
         }
     }
-    pub fn on_any_death(game: &mut Game, event: &OnAnyDeath, _fold: &mut (), _priority: ()){
-        for modifier in game.modifier_settings().modifiers.clone(){
-            modifier.1.on_any_death(game, event.dead_player);
+    pub fn on_any_death<M>(game: &mut Game, event: &OnAnyDeath, _fold: &mut (), _priority: ())
+    where for<'a> ModifierID: From<&'a M>, M: ModifierStateImpl
+    {
+        if let Some(state) = state::<M>(game) {
+            state.on_any_death(game, event.dead_player);
         }
     }
-    pub fn on_whisper(game: &mut Game, event: &OnWhisper, fold: &mut WhisperFold, priority: WhisperPriority) {
-        for modifier in game.modifier_settings().modifiers.clone(){
-            modifier.1.on_whisper(game, event, fold, priority);
+    pub fn on_whisper<M>(game: &mut Game, event: &OnWhisper, fold: &mut WhisperFold, priority: WhisperPriority)
+    where for<'a> ModifierID: From<&'a M>, M: ModifierStateImpl
+    {
+        if let Some(state) = state::<M>(game) {
+            state.on_whisper(game, event, fold, priority);
         }
     }
 }
@@ -155,6 +220,9 @@ mod macros {
             }
 
             impl ModifierID {
+                pub fn values()->Vec<ModifierID>{
+                    vec![$(Self::$name),*]
+                }
                 pub fn default_state(&self)->ModifierState{
                     match self{
                         $(
@@ -175,10 +243,17 @@ mod macros {
             }
 
             impl ModifierState {
-                fn on_midnight(self, game: &mut Game, priority: OnMidnightPriority) {
+                fn subscribe_to_events(&self, registry: &mut EventRegistry) {
+                    match self {
+                        $(
+                            ModifierState::$name(_) => <$file::$name as ModifierStateImpl>::subscribe_to_events(registry),
+                        )*
+                    }
+                }
+                fn on_midnight(self, game: &mut Game, fold: &mut OnMidnightFold, priority: OnMidnightPriority) {
                     match self {
                         $(
-                            ModifierState::$name(s) => s.on_midnight(game, priority),
+                            ModifierState::$name(s) => s.on_midnight(game, fold, priority),
                         )*
                     }
                 }
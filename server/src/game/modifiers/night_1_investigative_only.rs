@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::{
+    event::on_midnight::{OnMidnightFold, OnMidnightPriority},
+    player::PlayerReference, role_list::RoleSet, Game
+};
+
+use crate::{game::event::registry::EventRegistry, modifier_listener};
+
+use super::{ModifierStateImpl, ModifierID};
+
+/// Night 1 is only for investigating.
+/// Everyone whose role isn't a town investigative is roleblocked on night 1, and is told so.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+pub struct Night1InvestigativeOnly;
+
+impl From<&Night1InvestigativeOnly> for ModifierID{
+    fn from(_: &Night1InvestigativeOnly) -> Self {
+        ModifierID::Night1InvestigativeOnly
+    }
+}
+
+impl ModifierStateImpl for Night1InvestigativeOnly{
+    fn subscribe_to_events(registry: &mut EventRegistry) {
+        registry.subscribe(modifier_listener!(Night1InvestigativeOnly::on_midnight));
+    }
+    fn on_midnight(self, game: &mut Game, fold: &mut OnMidnightFold, priority: OnMidnightPriority) {
+        if priority != OnMidnightPriority::TopPriority || game.day_number() != 1 {return}
+
+        let investigatives = RoleSet::TownInvestigative.get_roles();
+        for player in PlayerReference::all_players(game) {
+            if player.alive(game) && !investigatives.contains(&player.role(game)) {
+                player.roleblock(game, fold, true);
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ModifierStateImpl, ModifierID};
+
+/// Every night is traced step by step, and the breakdown is shown in the synopsis after the game ends
+#[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+pub struct NightBreakdown;
+
+/*
+    There is modifier specific code in the night_trace component
+*/
+impl From<&NightBreakdown> for ModifierID{
+    fn from(_: &NightBreakdown) -> Self {
+        ModifierID::NightBreakdown
+    }
+}
+
+impl ModifierStateImpl for NightBreakdown{}
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::game::{components::graves::{grave::GraveInformation}, event::on_grave_added::OnGraveAdded, Game};
 
+use crate::{game::event::registry::EventRegistry, modifier_listener};
+
 use super::{ModifierStateImpl, ModifierID};
 
 #[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
@@ -13,6 +15,9 @@ impl From<&NoDeathCause> for ModifierID{
     }
 }
 impl ModifierStateImpl for NoDeathCause{
+    fn subscribe_to_events(registry: &mut EventRegistry) {
+        registry.subscribe(modifier_listener!(NoDeathCause::on_grave_added));
+    }
     fn on_grave_added(self, game: &mut Game, event: &OnGraveAdded, _fold: &mut (), _priority: ()) {
         match event.grave.deref(game).information.clone() {
             GraveInformation::Obscured => {},
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::game::{event::on_phase_start::OnPhaseStart, phase::{PhaseState, PhaseStateMachine}, Game};
 
+use crate::{game::event::registry::EventRegistry, modifier_listener};
+
 use super::{ModifierStateImpl, ModifierID};
 
 #[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
@@ -14,6 +16,9 @@ impl From<&AutoGuilty> for ModifierID{
 }
 
 impl ModifierStateImpl for AutoGuilty{
+    fn subscribe_to_events(registry: &mut EventRegistry) {
+        registry.subscribe(modifier_listener!(AutoGuilty::on_phase_start));
+    }
     fn on_phase_start(self, game: &mut Game, event: &OnPhaseStart, _fold: &mut (), _priority: ()) {
         match event.phase {
             PhaseState::Testimony { player_on_trial, .. }
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ModifierStateImpl, ModifierID};
+
+/// Every attack on night 1 fails, as if it never happened.
+/// Nobody is told they attacked or were attacked.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+pub struct NoNight1Attacks;
+
+/*
+    There is modifier specific code in NightAttack::attack
+*/
+impl From<&NoNight1Attacks> for ModifierID{
+    fn from(_: &NoNight1Attacks) -> Self {
+        ModifierID::NoNight1Attacks
+    }
+}
+
+impl ModifierStateImpl for NoNight1Attacks{}
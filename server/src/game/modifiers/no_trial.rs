@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::game::{event::on_phase_start::OnPhaseStart, phase::{PhaseState, PhaseStateMachine}, Game};
 
+use crate::{game::event::registry::EventRegistry, modifier_listener};
+
 use super::{ModifierStateImpl, ModifierID};
 
 #[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
@@ -14,13 +16,16 @@ impl From<&NoTrialPhases> for ModifierID{
 }
 
 impl ModifierStateImpl for NoTrialPhases{
+    fn subscribe_to_events(registry: &mut EventRegistry) {
+        registry.subscribe(modifier_listener!(NoTrialPhases::on_phase_start));
+    }
     fn on_phase_start(self, game: &mut Game, event: &OnPhaseStart, _fold: &mut (), _priority: ()) {
         match event.phase {
             PhaseState::Nomination { .. }
             | PhaseState::Adjournment { .. }
             | PhaseState::Testimony { .. }
             | PhaseState::Judgement { .. } => {
-                PhaseStateMachine::next_phase(game, Some(PhaseState::Dusk))
+                PhaseStateMachine::next_phase(game, Some(PhaseState::next_scheduled(game, None)))
             }
             _ => {}
         }
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::game::{event::on_whisper::{OnWhisper, WhisperFold, WhisperPriority}, Game};
 
+use crate::{game::event::registry::EventRegistry, modifier_listener};
+
 use super::{ModifierStateImpl, ModifierID};
 
 #[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
@@ -14,6 +16,9 @@ impl From<&NoWhispers> for ModifierID{
 }
 
 impl ModifierStateImpl for NoWhispers {
+    fn subscribe_to_events(registry: &mut EventRegistry) {
+        registry.subscribe(modifier_listener!(NoWhispers::on_whisper));
+    }
     fn on_whisper(self, _game: &mut Game, _event: &OnWhisper, fold: &mut WhisperFold, priority: WhisperPriority) {
         if WhisperPriority::Cancel == priority {
             fold.cancelled = true;
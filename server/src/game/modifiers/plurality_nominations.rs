@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::Game;
+
+use super::{ModifierState, ModifierStateImpl, ModifierID};
+
+/// The player with the most nomination votes at the end of nomination goes on trial, even without a majority
+#[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PluralityNominations {
+    pub tie_break: PluralityTieBreak,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum PluralityTieBreak {
+    #[default]
+    NoTrial,
+    Random,
+    /// Nomination is held again between the tied players. A tied revote puts nobody on trial.
+    Revote,
+}
+
+/*
+    There is modifier specific code in the count_nomination_and_start_trial() and PhaseState::end() functions
+*/
+impl From<&PluralityNominations> for ModifierID{
+    fn from(_: &PluralityNominations) -> Self {
+        ModifierID::PluralityNominations
+    }
+}
+impl ModifierStateImpl for PluralityNominations{}
+
+impl PluralityNominations {
+    /// None if plurality nominations are disabled
+    pub fn tie_break(game: &Game) -> Option<PluralityTieBreak> {
+        if let Some(ModifierState::PluralityNominations(plurality)) = game.modifier_settings().get_modifier(ModifierID::PluralityNominations) {
+            Some(plurality.tie_break)
+        } else {
+            None
+        }
+    }
+}
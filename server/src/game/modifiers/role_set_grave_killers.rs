@@ -5,6 +5,8 @@ use crate::game::{
     event::on_grave_added::OnGraveAdded, role_list::RoleSet, Game
 };
 
+use crate::{game::event::registry::EventRegistry, modifier_listener};
+
 use super::{ModifierStateImpl, ModifierID};
 
 #[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
@@ -16,6 +18,9 @@ impl From<&RoleSetGraveKillers> for ModifierID{
     }
 }
 impl ModifierStateImpl for RoleSetGraveKillers{
+    fn subscribe_to_events(registry: &mut EventRegistry) {
+        registry.subscribe(modifier_listener!(RoleSetGraveKillers::on_grave_added));
+    }
     fn on_grave_added(self, game: &mut Game, event: &OnGraveAdded, _fold: &mut (), _priority: ()) {
         let grave = event.grave;
         match grave.deref(game).information.clone() {
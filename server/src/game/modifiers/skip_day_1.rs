@@ -5,6 +5,8 @@ use crate::game::{
     phase::PhaseType::*, Game
 };
 
+use crate::{game::event::registry::EventRegistry, modifier_listener};
+
 use super::{ModifierStateImpl, ModifierID};
 
 #[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
@@ -17,6 +19,9 @@ impl From<&SkipDay1> for ModifierID{
 }
 
 impl ModifierStateImpl for SkipDay1{
+    fn subscribe_to_events(registry: &mut EventRegistry) {
+        registry.subscribe(modifier_listener!(SkipDay1::on_phase_start));
+    }
     fn on_phase_start(self, game: &mut Game, event: &OnPhaseStart, _fold: &mut (), _priority: ()) {
         match (event.phase.phase(), game.day_number()) {
             (Dusk, 1) |
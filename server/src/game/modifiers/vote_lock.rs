@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::{controllers::ControllerID, phase::PhaseType, Game};
+
+use super::{ModifierState, ModifierStateImpl, ModifierID};
+
+/// Nomination and verdict votes can't be changed in the last seconds of Nomination and Judgement
+#[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VoteLock {
+    pub seconds: u16,
+}
+
+/*
+    There is modifier specific code in the Controllers::validate_input() function
+*/
+impl From<&VoteLock> for ModifierID{
+    fn from(_: &VoteLock) -> Self {
+        ModifierID::VoteLock
+    }
+}
+impl ModifierStateImpl for VoteLock{}
+
+impl VoteLock {
+    pub fn votes_are_locked(game: &Game) -> bool {
+        let Some(ModifierState::VoteLock(VoteLock { seconds })) = game.modifier_settings().get_modifier(ModifierID::VoteLock) else {
+            return false
+        };
+
+        matches!(game.current_phase().phase(), PhaseType::Nomination | PhaseType::Judgement) &&
+        game.phase_machine.time_remaining.is_some_and(|time| time.as_secs() <= u64::from(*seconds))
+    }
+
+    pub fn controller_is_locked(game: &Game, id: &ControllerID) -> bool {
+        matches!(id, ControllerID::Nominate { .. } | ControllerID::Judge { .. }) &&
+        Self::votes_are_locked(game)
+    }
+}
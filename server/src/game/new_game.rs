@@ -3,9 +3,9 @@ use rand::{rngs::SmallRng, SeedableRng};
 use crate::{
     client_connection::ClientConnection, game::{
         Assignments, Game, RejectStartReason, abilities_component::Abilities, chat::{ChatComponent, PlayerChatGroups}, components::{
-            blocked::BlockedComponent, confused::Confused, cult::Cult, detained::Detained, enfranchise::EnfranchiseComponent, fast_forward::FastForwardComponent, fragile_vest::FragileVestsComponent, graves::Graves, hide_votes_message::HideVotesMessage, insider_group::{InsiderGroupID, InsiderGroups}, mafia::Mafia, mafia_recruits::MafiaRecruits, pitchfork_item::PitchforkItemComponent, poison::Poison, puppeteer_marionette::PuppeteerMarionette, role::RoleComponent, role_reveal::RevealedPlayersComponent, silenced::Silenced, synopsis::SynopsisTracker, tags::Tags, verdicts_today::VerdictsToday, win_condition::WinConditionComponent
-        }, controllers::Controllers, event::{AsInvokable as _, Invokable as _, on_game_start::OnGameStart}, game_client::GameClient, modifiers::ModifierID, phase::PhaseStateMachine, player::{Player, PlayerInitializeParameters, PlayerReference}, role_list_generation::{OutlineListAssignment, RoleListGenerator}, settings::Settings, spectator::{Spectator, SpectatorInitializeParameters, spectator_pointer::SpectatorPointer}
-    }, packet::ToClientPacket, room::{RoomClientID, name_validation::generate_random_name}, vec_map::VecMap
+            blocked::BlockedComponent, confused::Confused, cult::Cult, detained::Detained, enfranchise::EnfranchiseComponent, fast_forward::FastForwardComponent, fragile_vest::FragileVestsComponent, graves::Graves, hide_votes_message::HideVotesMessage, insider_group::{InsiderGroupID, InsiderGroups}, mafia::Mafia, mafia_recruits::MafiaRecruits, pitchfork_item::PitchforkItemComponent, poison::Poison, puppeteer_marionette::PuppeteerMarionette, role::RoleComponent, role_reveal::RevealedPlayersComponent, silenced::Silenced, synopsis::SynopsisTracker, tags::Tags, verdicts_today::VerdictsToday, nomination_revote::NominationRevote, pause::Pause, moderation::Moderation, inactivity::Inactivity, vote_history::VoteHistory, night_trace::NightTrace, win_condition::WinConditionComponent
+        }, controllers::Controllers, event::{AsInvokable as _, Invokable as _, on_game_start::OnGameStart, registry::EventRegistry}, game_client::GameClient, modifiers::ModifierID, phase::PhaseStateMachine, player::{Player, PlayerInitializeParameters, PlayerReference}, role_list_generation::{OutlineListAssignment, RoleListGenerator}, settings::Settings, spectator::{Spectator, SpectatorInitializeParameters, spectator_feed::SpectatorFeed, spectator_pointer::SpectatorPointer}
+    }, lobby::lobby_chat::LobbyChat, packet::ToClientPacket, room::{RoomClientID, name_validation::generate_random_name, room_access::RoomAccess}, vec_map::VecMap
 };
 
 impl Game{
@@ -13,6 +13,8 @@ impl Game{
     pub fn new(
         room_name: String,
         settings: Settings,
+        lobby_chat: LobbyChat,
+        access: RoomAccess,
         clients: VecMap<RoomClientID, GameClient>,
         players: Vec<PlayerInitializeParameters>,
         spectators: Vec<SpectatorInitializeParameters>
@@ -21,6 +23,13 @@ impl Game{
         if settings.phase_times.game_ends_instantly() {
             return Err(RejectStartReason::ZeroTimeGame);
         }
+        let phase_schedule = settings.game_phase_schedule();
+        if !phase_schedule.is_valid() {
+            return Err(RejectStartReason::InvalidPhaseSchedule);
+        }
+        if !settings.phase_times.percentages_are_valid() {
+            return Err(RejectStartReason::InvalidPhaseTimes);
+        }
         let mut rng = SmallRng::seed_from_u64(settings.random_seed.unwrap_or_else(rand::random));
 
         let mut role_generation_tries = 0u8;
@@ -62,7 +71,8 @@ impl Game{
                         &new_players_names
                             .iter()
                             .map(|p: &String|p.as_str())
-                            .collect::<Vec<&str>>()
+                            .collect::<Vec<&str>>(),
+                        &mut rng
                     )
                 }else{
                     player.name.clone()
@@ -82,6 +92,8 @@ impl Game{
 
             let mut game = Self {
                 room_name: room_name.clone(),
+                lobby_chat: lobby_chat.clone(),
+                access: access.clone(),
                 clients: clients.clone(),
                 // pitchfork: Pitchfork::new(num_players),
 
@@ -90,9 +102,11 @@ impl Game{
                 rng: rng.clone(),
                 spectators: spectators.clone().into_iter().map(Spectator::new).collect(),
                 spectator_chat_messages: Vec::new(),
+                spectator_feed: SpectatorFeed::new(settings.spectators.feed_delay),
                 players: new_players.into_boxed_slice(),
-                phase_machine: PhaseStateMachine::new(settings.phase_times.clone()),
+                phase_machine: PhaseStateMachine::new(settings.phase_times.clone(), phase_schedule.clone()),
                 abilities: Abilities::new(&assignments, &settings.enabled_roles),
+                event_registry: EventRegistry::new(&settings),
                 settings,
 
                 player_chat_groups: PlayerChatGroups::new(),
@@ -105,6 +119,12 @@ impl Game{
                 puppeteer_marionette: PuppeteerMarionette::default(),
                 mafia_recruits: MafiaRecruits::default(),
                 verdicts_today: VerdictsToday::default(),
+                nomination_revote: NominationRevote::default(),
+                pause: Pause::default(),
+                moderation: Moderation::default(),
+                inactivity: Inactivity::default(),
+                vote_history: VoteHistory::default(),
+                night_trace: NightTrace::default(),
                 blocked: unsafe{BlockedComponent::new(num_players)},
                 poison: Poison::default(),
                 pitchfork_item: unsafe{PitchforkItemComponent::new(num_players)},
@@ -146,6 +166,8 @@ impl Game{
             return Err(RejectStartReason::RoleListCannotCreateRoles);
         }
         
+        let game_start = SpectatorPointer::game_start_packets(&game);
+        game.spectator_feed.set_game_start(game_start);
         game.send_packet_to_all(ToClientPacket::StartGame);
         
         Abilities::set_abilities_states_to_default(&mut game);
@@ -1,14 +1,18 @@
+use rand::{rngs::SmallRng, SeedableRng};
+
 use crate::{
     game::{
-        abilities::role_abilities::RoleAbility, abilities_component::{ability::Ability, ability_id::AbilityID}, components::fast_forward::FastForwardComponent, event::{AsInvokable as _, Invokable as _}, role::Role
+        abilities::role_abilities::RoleAbility, abilities_component::{ability::Ability, ability_id::AbilityID}, components::{fast_forward::FastForwardComponent, moderation::Moderation, pause::Pause}, event::{AsInvokable as _, Invokable as _}, role::Role
     }, lobby::{lobby_client::LobbyClient, Lobby}, log, packet::{ToClientPacket, ToServerPacket},
     room::{RemoveRoomClientResult, RoomClientID, RoomState}, vec_map::VecMap, websocket_connections::connection::ClientSender
 };
 
 use super::{
     event::on_game_ending::OnGameEnding,
+    chat::ChatMessageVariant,
     game_client::GameClientLocation,
     game_conclusion::GameConclusion,
+    phase::PhaseStateMachine,
     player::PlayerReference,
     role::RoleState,
     spectator::spectator_pointer::SpectatorPointer, Game
@@ -17,16 +21,18 @@ use super::{
 
 
 pub enum GameClientMessageResult {
-    BackToLobby(Lobby),
+    BackToLobby(Box<Lobby>),
     Close,
     None
 }
 
 impl Game {
-    #[expect(clippy::match_single_binding, unused, reason="Surely spectators will do something in the future")]
     pub fn on_spectator_message(&mut self, sender_ref: SpectatorPointer, incoming_packet: ToServerPacket){
-        match incoming_packet {
-            _ => {}
+        if 
+            let ToServerPacket::SetWaitingToReplace { waiting } = incoming_packet &&
+            let Some(spectator) = sender_ref.deref_mut(self)
+        {
+            spectator.waiting_to_replace = waiting;
         }
     }
     
@@ -69,9 +75,12 @@ impl Game {
 
                 self.send_to_all(ToClientPacket::BackToLobby);
 
-                let lobby = Lobby::new_from_game(self.room_name.clone(), self.settings.clone(), new_clients);
+                let lobby = Lobby::new_from_game(
+                    self.room_name.clone(), self.settings.clone(), new_clients, self.lobby_chat.clone(), self.access.clone(),
+                    SmallRng::from_rng(&mut self.rng)
+                );
 
-                return GameClientMessageResult::BackToLobby(lobby);
+                return GameClientMessageResult::BackToLobby(Box::new(lobby));
             }
             ToServerPacket::HostForceEndGame => {
                 if let Some(player) = self.clients.get(&room_client_id)
@@ -87,6 +96,52 @@ impl Game {
                 
                 FastForwardComponent::skip(self);
             }
+            ToServerPacket::HostPauseGame => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.host {break 'packet_match}
+
+                Pause::pause(self);
+            }
+            ToServerPacket::HostResumeGame { countdown } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.host {break 'packet_match}
+
+                Pause::resume(self, countdown);
+            }
+            ToServerPacket::HostAdjustPhaseTime { seconds } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.host {break 'packet_match}
+
+                PhaseStateMachine::adjust_time_remaining(self, seconds);
+            }
+            ToServerPacket::HostSetPhaseTimes { phase_time_settings } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.host {break 'packet_match}
+
+                if !PhaseStateMachine::set_phase_times(self, phase_time_settings) {
+                    sender_player_ref.add_private_chat_message(self, ChatMessageVariant::InvalidPhaseTimes);
+                }
+            }
+            ToServerPacket::HostModkill { id } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.host {break 'packet_match}
+                if let Some(player) = self.clients.get(&id) && let GameClientLocation::Player(player) = player.client_location {
+                    Moderation::modkill(self, player);
+                }
+            }
+            ToServerPacket::HostReplacePlayer { id, replacement_id } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.host {break 'packet_match}
+
+                Moderation::replace_player(self, id, replacement_id);
+            }
+            ToServerPacket::HostForceReveal { id } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.host {break 'packet_match}
+                if let Some(player) = self.clients.get(&id) && let GameClientLocation::Player(player) = player.client_location {
+                    Moderation::force_reveal(self, player);
+                }
+            }
+            ToServerPacket::CreateInviteCode => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.host {break 'packet_match}
+
+                if let Some(code) = self.access.create_invite_code() {
+                    sender_player_ref.send_packet(self, ToClientPacket::InviteCode { code });
+                }
+            }
             ToServerPacket::HostDataRequest => {
                 if let Some(player) = self.clients.get(&room_client_id) && !player.host {break 'packet_match}
 
@@ -2,7 +2,7 @@ use std::{ops::Div, time::Duration};
 
 use serde::{Serialize, Deserialize};
 
-use crate::game::{components::graves::{grave::Grave, Graves}, event::{AsInvokable as _, Invokable as _}, modifiers::{hidden_nomination_votes::HiddenNominationVotes, hidden_verdict_votes::HiddenVerdictVotes, ModifierID}};
+use crate::{game::{components::{graves::{grave::Grave, Graves}, nomination_revote::NominationRevote}, event::{AsInvokable as _, Invokable as _}, modifiers::{hidden_nomination_votes::HiddenNominationVotes, hidden_verdict_votes::HiddenVerdictVotes, ModifierID}}, packet::ToClientPacket};
 
 use super::{
     chat::{ChatGroup, ChatMessageVariant},
@@ -10,6 +10,7 @@ use super::{
         before_phase_end::BeforePhaseEnd,
         on_midnight::{OnMidnight, OnMidnightFold}, on_phase_start::OnPhaseStart,
     },
+    phase_schedule::{PhaseSchedule, ScheduledPhase},
     player::PlayerReference, settings::PhaseTimeSettings, Game
 };
 
@@ -72,17 +73,27 @@ impl Ord for PhaseState{
 
 pub struct PhaseStateMachine {
     pub time_remaining: Option<Duration>,
+    /// How long the current phase has been going, not counting time the game was paused
+    pub time_elapsed: Duration,
     pub current_state: PhaseState,
     pub day_number: u8, // Hopefully nobody is having more than 256 days anyway
+    /// The phase schedule this game follows, which modifiers might have changed from the one in the settings
+    pub schedule: PhaseSchedule,
+    /// Where the game is in the phase schedule, None during Briefing
+    pub schedule_position: Option<usize>,
 }
 
 impl PhaseStateMachine {
-    pub fn new(times: PhaseTimeSettings) -> Self {
+    pub fn new(times: PhaseTimeSettings, schedule: PhaseSchedule) -> Self {
         let current_state = PhaseState::Briefing;
 
         Self {
-            time_remaining: times.get_time_for(current_state.phase()),
+            // Nobody has died yet, so the endgame rules can't apply
+            time_remaining: times.get_scaled_time_for(current_state.phase(), 1, usize::MAX),
+            time_elapsed: Duration::ZERO,
             day_number: 1,
+            schedule,
+            schedule_position: None,
             current_state,
         }
     }
@@ -95,29 +106,71 @@ impl PhaseStateMachine {
         self.time_remaining = time;
     }
 
+    /// Adds time to the current phase, or takes it away if `seconds` is negative.
+    /// Untimed phases stay untimed.
+    pub fn adjust_time_remaining(game: &mut Game, seconds: i32) {
+        let Some(time_remaining) = game.phase_machine.time_remaining else {return};
+        let change = Duration::from_secs(seconds.unsigned_abs().into());
+
+        let new_time = if seconds.is_negative() {
+            time_remaining.saturating_sub(change)
+        } else {
+            time_remaining.saturating_add(change).min(Duration::from_secs(u16::MAX.into()))
+        };
+        game.phase_machine.time_remaining = Some(new_time);
+
+        // The time can't go below zero or above u16::MAX seconds, so this might be less than the host asked for
+        let applied = i64::try_from(new_time.as_secs()).unwrap_or(i64::MAX)
+            .saturating_sub(i64::try_from(time_remaining.as_secs()).unwrap_or(i64::MAX));
+        let seconds = i32::try_from(applied).unwrap_or(seconds);
+        game.add_message_to_chat_group(ChatGroup::All, ChatMessageVariant::PhaseTimeAdjusted { seconds });
+        Self::send_time_left(game);
+    }
+
+    /// Changes phase times for every phase after this one.
+    /// Returns false, changing nothing, if the new times are invalid or would end the game instantly.
+    pub fn set_phase_times(game: &mut Game, phase_times: PhaseTimeSettings) -> bool {
+        if !phase_times.percentages_are_valid() || phase_times.game_ends_instantly() {return false}
+
+        game.settings.phase_times = phase_times.clone();
+
+        game.add_message_to_chat_group(ChatGroup::All, ChatMessageVariant::PhaseTimesChanged);
+        game.send_packet_to_all(ToClientPacket::PhaseTimes { phase_time_settings: phase_times });
+        Self::send_time_left(game);
+        true
+    }
+
+    fn send_time_left(game: &mut Game) {
+        game.send_packet_to_all(ToClientPacket::PhaseTimeLeft {
+            seconds_left: game.phase_machine.time_remaining.map(|o|o.as_secs().try_into().expect("Phase time should be below 18 hours"))
+        });
+    }
+
     pub fn next_phase(game: &mut Game, force_next_phase: Option<PhaseState>) {
         BeforePhaseEnd::new(game.current_phase().phase()).as_invokable().invoke(game);
         let mut new_phase = PhaseState::end(game);
 
         if let Some(forced_new_phase) = force_next_phase {
+            // A revote the natural end of the phase started would otherwise carry on into whatever nomination comes next
+            NominationRevote::finish(game);
             new_phase = forced_new_phase;
         }
 
+        game.phase_machine.schedule_position = game.phase_machine.schedule.position_of(game.phase_machine.schedule_position, new_phase.phase());
         game.phase_machine.current_state = new_phase;
         game.phase_machine.time_remaining = PhaseStateMachine::get_phase_time_length(game, game.current_phase().phase());
+        game.phase_machine.time_elapsed = Duration::ZERO;
 
         PhaseState::start(game);
         OnPhaseStart::new(game.current_phase().clone()).as_invokable().invoke(game);
     }
 
     pub fn get_phase_time_length(game: &Game, phase: PhaseType) -> Option<Duration> {
-        let mut time = game.settings.phase_times.get_time_for(phase);
-        //if there are less than 3 players alive then the game is sped up by 2x
-        if PlayerReference::all_players(game).filter(|p|p.alive(game)).count() <= 3{
-            time = time.map(|o|o.div(2));
-        }
-
-        time
+        game.settings.phase_times.get_scaled_time_for(
+            phase,
+            game.day_number(),
+            PlayerReference::all_players(game).filter(|p|p.alive(game)).count()
+        )
     }
 }
 
@@ -162,7 +215,8 @@ impl PhaseState {
 
                 for player_ref in PlayerReference::all_players(game) {
                     if player_ref.night_died(&last_night) {
-                        Graves::add_grave(game, Grave::from_player_night(game, &last_night, player_ref));
+                        let grave = Grave::from_player_night(game, &last_night, player_ref);
+                        Graves::add_grave(game, grave);
                     }
                 }
                 for player_ref in PlayerReference::all_players(game) {
@@ -186,7 +240,7 @@ impl PhaseState {
                         ChatMessageVariant::PlayerNominated {
                             player_index: player_on_trial,
                             players_voted: PlayerReference::all_players(game)
-                                .filter(|player_ref| player_ref.chosen_vote(game) == Some(player_on_trial))
+                                .filter(|player_ref| player_ref.voted_for(game, player_on_trial))
                                 .collect()
                         }
                     );
@@ -205,46 +259,84 @@ impl PhaseState {
         
     }
     
+    /// Adjournment is skipped entirely if it's disabled in the trial settings
+    fn adjourn(game: &Game, trials_left: u8) -> PhaseState {
+        if game.settings.trials.adjournment {
+            Self::Adjournment { trials_left }
+        } else {
+            Self::after_adjournment(game, trials_left)
+        }
+    }
+    fn after_adjournment(game: &Game, trials_left: u8) -> PhaseState {
+        if trials_left == 0 {
+            Self::next_scheduled(game, None)
+        } else {
+            Self::Nomination { trials_left }
+        }
+    }
+
+    /// The first phase of the next step in the phase schedule.
+    /// Trials are skipped on days that don't have any.
+    pub fn next_scheduled(game: &Game, mut last_night: Option<OnMidnightFold>) -> PhaseState {
+        let schedule = &game.phase_machine.schedule;
+        let mut position = game.phase_machine.schedule_position;
+
+        for _ in 0..=schedule.first_day.len().saturating_add(schedule.cycle.len()) {
+            let next = schedule.next_position(position);
+            position = Some(next);
+
+            match schedule.get(next) {
+                Some(ScheduledPhase::Dusk) => return Self::Dusk,
+                Some(ScheduledPhase::Night) => return Self::Night,
+                Some(ScheduledPhase::Discussion) => return Self::Discussion,
+                Some(ScheduledPhase::Obituary) => return Self::Obituary { last_night: last_night.take().unwrap_or_default() },
+                Some(ScheduledPhase::Trials) => {
+                    let trials_left = game.settings.trials.trials_on_day(game.day_number());
+                    if trials_left > 0 {
+                        return Self::Nomination { trials_left };
+                    }
+                },
+                None => break
+            }
+        }
+
+        Self::Night
+    }
+
     /// Returns what phase should come next
     pub fn end(game: &mut Game) -> PhaseState {
         match *game.current_phase() {
-            PhaseState::Briefing => {
-                Self::Dusk
-            },
-            PhaseState::Obituary { .. }=> {
-                Self::Discussion
-            },
-            PhaseState::Discussion => {
-                Self::Nomination {
-                    trials_left: 3,
-                }
+            PhaseState::Briefing
+            | PhaseState::Obituary { .. }
+            | PhaseState::Discussion
+            | PhaseState::Dusk => {
+                Self::next_scheduled(game, None)
             },
             PhaseState::Nomination {trials_left, ..} => {
-                if game.modifier_settings().is_enabled(ModifierID::UnscheduledNominations){
+                let was_revote = NominationRevote::finish(game);
+
+                if
+                    game.modifier_settings().is_enabled(ModifierID::UnscheduledNominations) &&
+                    !game.modifier_settings().is_enabled(ModifierID::PluralityNominations)
+                {
                     if trials_left == 0 {
-                        Self::Dusk
+                        Self::next_scheduled(game, None)
                     } else {
-                        Self::Adjournment { trials_left: 0 }
+                        Self::adjourn(game, 0)
                     }
                 }else if let Some(player_on_trial) = game.count_nomination_and_start_trial(false){    
                     Self::Testimony{
                         trials_left: trials_left.saturating_sub(1), 
                         player_on_trial,
                     }
-                } else if trials_left > 0 {
-                    Self::Adjournment {trials_left: trials_left.saturating_sub(1)}
+                } else if !was_revote && game.start_nomination_revote() {
+                    Self::Nomination { trials_left }
                 } else {
-                    Self::Adjournment {trials_left}
+                    Self::adjourn(game, trials_left.saturating_sub(1))
                 }
             },
             PhaseState::Adjournment {trials_left} => {
-                if trials_left == 0 {
-                    Self::Dusk
-                } else {
-                    Self::Nomination {
-                        trials_left,
-                    }
-                }
+                Self::after_adjournment(game, trials_left)
             },
             PhaseState::Testimony { trials_left, player_on_trial } => {
                 Self::Judgement { trials_left, player_on_trial }
@@ -287,7 +379,7 @@ impl PhaseState {
                 if hang {
                     Self::FinalWords { player_on_trial }
                 } else if trials_left == 0 {
-                    Self::Dusk
+                    Self::next_scheduled(game, None)
                 }else{
                     Self::Nomination { trials_left }
                 }
@@ -295,16 +387,15 @@ impl PhaseState {
             PhaseState::FinalWords { player_on_trial } => {
                 player_on_trial.die_and_add_grave(game, Grave::from_player_lynch(game, player_on_trial));
 
-                Self::Dusk
-            },
-            PhaseState::Dusk => {
-                Self::Night
+                Self::next_scheduled(game, None)
             },
             PhaseState::Night => {
                 let mut event = OnMidnight::new(game);
                 let borrow = event.as_invokable();
                 borrow.invoke(game);
-                Self::Obituary { last_night: event.1 }
+                event.1.visits().debug_assert_materialized();
+                // The schedule always has an obituary after a night
+                Self::next_scheduled(game, Some(event.1))
             },
             PhaseState::Recess => Self::Recess
         }
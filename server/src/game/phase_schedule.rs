@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+
+use super::phase::PhaseType;
+
+/// A step of the phase schedule.
+/// `Trials` covers every phase from nomination to final words, and is skipped on days without trials.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ScheduledPhase {
+    Dusk,
+    Night,
+    Obituary,
+    Discussion,
+    Trials,
+}
+
+impl ScheduledPhase {
+    /// None for phases that aren't part of the schedule
+    pub fn from_phase(phase: PhaseType) -> Option<Self> {
+        match phase {
+            PhaseType::Dusk => Some(Self::Dusk),
+            PhaseType::Night => Some(Self::Night),
+            PhaseType::Obituary => Some(Self::Obituary),
+            PhaseType::Discussion => Some(Self::Discussion),
+            PhaseType::Nomination
+            | PhaseType::Adjournment
+            | PhaseType::Testimony
+            | PhaseType::Judgement
+            | PhaseType::FinalWords => Some(Self::Trials),
+            PhaseType::Briefing
+            | PhaseType::Recess => None,
+        }
+    }
+}
+
+/// The order phases happen in after Briefing.
+/// The game goes through `first_day` once, then repeats `cycle` until the game ends.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhaseSchedule {
+    pub first_day: Vec<ScheduledPhase>,
+    pub cycle: Vec<ScheduledPhase>,
+}
+
+impl Default for PhaseSchedule {
+    fn default() -> Self {
+        Self {
+            first_day: vec![ScheduledPhase::Dusk, ScheduledPhase::Night],
+            cycle: vec![
+                ScheduledPhase::Obituary,
+                ScheduledPhase::Discussion,
+                ScheduledPhase::Trials,
+                ScheduledPhase::Dusk,
+                ScheduledPhase::Night,
+            ],
+        }
+    }
+}
+
+impl PhaseSchedule {
+    const MAX_LENGTH: usize = 20;
+
+    /// A position is an index into the first day followed by the cycle.
+    pub fn get(&self, position: usize) -> Option<ScheduledPhase> {
+        self.first_day.iter().chain(self.cycle.iter()).nth(position).copied()
+    }
+
+    /// The position after this one. None is the Briefing, before the schedule begins.
+    pub fn next_position(&self, position: Option<usize>) -> usize {
+        let Some(position) = position else { return 0 };
+        let next = position.saturating_add(1);
+
+        if next >= self.first_day.len().saturating_add(self.cycle.len()) {
+            self.first_day.len()
+        } else {
+            next
+        }
+    }
+
+    /// Finds the position of a phase that was entered from `position`.
+    /// Phases from the same step don't move the position, and phases outside the schedule are ignored.
+    pub fn position_of(&self, position: Option<usize>, phase: PhaseType) -> Option<usize> {
+        let Some(step) = ScheduledPhase::from_phase(phase) else { return position };
+        if position.and_then(|position| self.get(position)) == Some(step) {
+            return position;
+        }
+
+        let mut next = position;
+        for _ in 0..self.first_day.len().saturating_add(self.cycle.len()) {
+            let candidate = self.next_position(next);
+            if self.get(candidate) == Some(step) {
+                return Some(candidate);
+            }
+            next = Some(candidate);
+        }
+        position
+    }
+
+    /// Checks the invariants the rest of the game relies on:
+    /// - The cycle has a night, so the game keeps moving forward
+    /// - Every night is followed by an obituary, which carries the results of the night
+    /// - Every obituary follows a night
+    /// - The same step never happens twice in a row, so it's always clear which step a phase belongs to
+    pub fn is_valid(&self) -> bool {
+        if self.cycle.is_empty() || self.first_day.len().saturating_add(self.cycle.len()) > Self::MAX_LENGTH {
+            return false;
+        }
+        if !self.cycle.contains(&ScheduledPhase::Night) {
+            return false;
+        }
+
+        // None is the Briefing
+        let sequence: Vec<Option<ScheduledPhase>> = std::iter::once(None)
+            .chain(self.first_day.iter().copied().map(Some))
+            .chain(self.cycle.iter().copied().map(Some))
+            .chain(self.cycle.first().copied().map(Some))
+            .collect();
+
+        sequence.windows(2).all(|pair| match pair {
+            [before, after] => {
+                before != after &&
+                (*before == Some(ScheduledPhase::Night)) == (*after == Some(ScheduledPhase::Obituary))
+            }
+            _ => true
+        })
+    }
+}
@@ -6,8 +6,12 @@ use crate::game::{
 };
 
 use super::PlayerReference;
+use crate::{event_listener, game::event::registry::EventRegistry};
 
 impl PlayerReference {
+    pub fn subscribe_to_events(registry: &mut EventRegistry){
+        registry.subscribe(event_listener!(PlayerReference::on_midnight));
+    }
 
     pub fn on_midnight(game: &mut Game, _event: &OnMidnight, midnight_variables: &mut OnMidnightFold, priority: OnMidnightPriority){
         for player in PlayerReference::all_players(game){
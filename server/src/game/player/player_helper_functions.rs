@@ -95,6 +95,11 @@ impl PlayerReference{
             None
         }
     }
+    /// Approval nominations let a player vote for more than one player
+    pub fn voted_for(&self, game: &Game, target: PlayerReference) -> bool {
+        ControllerID::nominate(*self).get_player_list_selection(game)
+            .is_some_and(|PlayerListSelection(players)| players.contains(&target))
+    }
 
     pub fn ability_deactivated_from_death(&self, game: &Game) -> bool {
         !(
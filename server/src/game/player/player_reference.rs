@@ -26,11 +26,13 @@ impl PlayerReference{
         PlayerReference { index }
     }
     pub fn deref<'a>(&self, game: &'a Game)->&'a Player{
+        debug_assert!((self.index as usize) < game.players.len(), "player {} doesn't exist", self.index);
         unsafe { 
             game.players.get_unchecked(self.index as usize)
         }
     }
     pub fn deref_mut<'a>(&self, game: &'a mut Game)->&'a mut Player{
+        debug_assert!((self.index as usize) < game.players.len(), "player {} doesn't exist", self.index);
         unsafe {
             game.players.get_unchecked_mut(self.index as usize)
         }
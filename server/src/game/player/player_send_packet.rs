@@ -1,7 +1,7 @@
 use crate::{
     client_connection::ClientConnection, game::{
         Game, GameOverReason, chat::{ChatComponent, ChatMessageVariant}, components::{
-            graves::grave_reference::GraveReference, insider_group::InsiderGroups, role::RoleComponent, tags::Tags
+            graves::grave_reference::GraveReference, insider_group::InsiderGroups, pause::Pause, role::RoleComponent, tags::Tags
         }
     }, packet::ToClientPacket, websocket_connections::connection::ClientSender
 };
@@ -117,6 +117,7 @@ impl PlayerReference{
                 day_number: game.phase_machine.day_number 
             },
             ToClientPacket::PhaseTimeLeft { seconds_left: game.phase_machine.time_remaining.map(|o|o.as_secs().try_into().expect("Phase time should be below 18 hours")) },
+            Pause::packet(game),
             ToClientPacket::GameInitializationComplete
         ]);
     }
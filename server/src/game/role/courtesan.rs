@@ -15,7 +15,7 @@ impl RoleStateTrait for Courtesan {
     fn on_midnight(mut self, game: &mut Game, _id: &AbilityID, actor_ref: PlayerReference, midnight_variables: &mut OnMidnightFold, priority: OnMidnightPriority) {
         if priority != OnMidnightPriority::Roleblock {return;}
         let mut previous = Vec::new();
-        for target in Visits::into_iter(midnight_variables).default_targets(actor_ref, Role::Courtesan){
+        for target in Visits::into_iter(midnight_variables).default_targets(actor_ref, Role::Courtesan).collect::<Vec<_>>(){
             previous.push(target);
             target.roleblock(game, midnight_variables, true);
         }
@@ -91,7 +91,7 @@ impl RoleStateTrait for Engineer {
             }
             OnMidnightPriority::Kill => {
                 if let Trap::Set { target, .. } = self.trap {
-                    for visit in Visits::into_iter(midnight_variables) {
+                    for visit in Visits::into_iter(midnight_variables).collect::<Vec<_>>() {
                         if 
                             visit.attack &&
                             visit.target == target &&
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, iter};
+use std::iter;
 
 use serde::Serialize;
 use crate::{game::{components::attack::night_attack::NightAttack, prelude::*}, vec_map, vec_set};
@@ -13,12 +13,12 @@ impl RoleStateTrait for Lich {
     type ClientAbilityState = Lich;
     fn on_midnight(self, game: &mut Game, id: &AbilityID, actor_ref: PlayerReference, midnight_variables: &mut OnMidnightFold, priority: OnMidnightPriority) {
 
-        if let Some(target) = Visits::into_iter(midnight_variables)
+        let target = Visits::into_iter(midnight_variables)
             .with_tag(VisitTag::Ability { ability: *id, id: Self::WARD_ID.cast_unsigned() })
             .with_visitor(actor_ref)
             .map_target()
-            .next()
-        {
+            .next();
+        if let Some(target) = target {
             target.ward_night_action(game, midnight_variables, priority);
         }
 
@@ -32,13 +32,13 @@ impl RoleStateTrait for Lich {
                 );
             },
             OnMidnightPriority::Transporter => {
-                let mut targets = Visits::into_iter(midnight_variables)
+                let targets: Vec<_> = Visits::into_iter(midnight_variables)
                     .with_tag(VisitTag::Ability { ability: *id, id: Self::TRANSPORT_ID.cast_unsigned() })
                     .with_visitor(actor_ref)
-                    .map_target();
+                    .map_target()
+                    .collect();
+                let &[a, b, ..] = targets.as_slice() else {return};
 
-                let Some(a) = targets.next() else {return};
-                let Some(b) = targets.next() else {return};
                 
                 Transport::transport(
                     midnight_variables, TransportPriority::Transporter,
@@ -48,13 +48,13 @@ impl RoleStateTrait for Lich {
                 );
             },
             OnMidnightPriority::Warper => {
-                let mut transporter_visits = Visits::into_iter(midnight_variables)
+                let transporter_visits: Vec<_> = Visits::into_iter(midnight_variables)
                     .with_tag(VisitTag::Ability { ability: *id, id: Self::WARP_ID.cast_unsigned() })
                     .with_visitor(actor_ref)
-                    .map_target();
+                    .map_target()
+                    .collect();
+                let &[first_visit, second_visit, ..] = transporter_visits.as_slice() else {return};
 
-                let Some(first_visit) = transporter_visits.next() else {return};
-                let Some(second_visit) = transporter_visits.next() else {return};
                 
                 Transport::transport(
                     midnight_variables, TransportPriority::Warper, 
@@ -64,12 +64,12 @@ impl RoleStateTrait for Lich {
                 );
             },
             OnMidnightPriority::Roleblock => {
-                if let Some(target_ref) = Visits::into_iter(midnight_variables)
+                let target_ref = Visits::into_iter(midnight_variables)
                     .with_tag(VisitTag::Ability { ability: *id, id: Self::ROLEBLOCK_ID.cast_unsigned() })
                     .with_visitor(actor_ref)
                     .map_target()
-                    .next()
-                {
+                    .next();
+                if let Some(target_ref) = target_ref {
                     target_ref.roleblock(game, midnight_variables, true);
                 }
             }
@@ -90,12 +90,12 @@ impl RoleStateTrait for Lich {
             OnMidnightPriority::Kill => {
                 if game.day_number() == 1 {return}
 
-                if let Some(target) = Visits::into_iter(midnight_variables)
+                let target = Visits::into_iter(midnight_variables)
                     .with_tag(VisitTag::Ability { ability: *id, id: Self::CHOOSE_ATTACK_ID.cast_unsigned() })
                     .with_visitor(actor_ref)
                     .map_target()
-                    .next()
-                {
+                    .next();
+                if let Some(target) = target {
                     NightAttack::new()
                         .attackers([actor_ref])
                         .grave_killer(Role::Lich)
@@ -355,7 +355,7 @@ impl Lich{
 
         //// ATTACK
         // Count votes into map
-        let mut target_vote_map = HashMap::<PlayerReference, u8>::default();
+        let mut target_vote_map = vec_map::VecMap::<PlayerReference, u8>::new();
         PlayerReference::all_players(game)
             .filter_map(|player|
                 ControllerID::LichVote { lich: actor_ref, player }
@@ -364,8 +364,8 @@ impl Lich{
                     .copied()
             )
             .for_each(|target|{
-                let votes = target_vote_map.entry(target).or_default();
-                *votes = (*votes).saturating_add(1);
+                let votes = target_vote_map.get(&target).copied().unwrap_or_default();
+                target_vote_map.insert(target, votes.saturating_add(1));
             });
 
         // Find max votes
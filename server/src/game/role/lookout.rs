@@ -16,6 +16,8 @@ impl RoleStateTrait for Lookout {
         let Some(visit) = Visits::default_visit(midnight_variables, actor_ref, Role::Lookout) else {return};
             
         let mut players: Vec<PlayerReference> = visit.target.lookout_seen_players(midnight_variables, visit).collect();
+        // Sorted first so the result doesn't depend on which visitor's ability happened to run first
+        players.sort();
         players.shuffle(&mut game.rng);
         
         actor_ref.push_night_message(midnight_variables, ChatMessageVariant::LookoutResult { players });
@@ -14,13 +14,17 @@ impl RoleStateTrait for Pawn {
     fn on_midnight(self, game: &mut Game, _id: &AbilityID, actor_ref: PlayerReference, midnight_variables: &mut OnMidnightFold, priority: OnMidnightPriority) {
         if priority != OnMidnightPriority::Investigative {return}
 
-        Visits::into_iter(midnight_variables)
+        let targets: Vec<_> = Visits::into_iter(midnight_variables)
             .with_direct()
             .with_visitor(actor_ref)
             .map_target()
             .filter(|player|!player.win_condition(game).friends_with_conclusion(GameConclusion::Town))
             .filter(|player|*player != actor_ref)
-            .for_each(|player|player.push_night_message(midnight_variables, ChatMessageVariant::PawnVisitedYou))
+            .collect();
+
+        for player in targets {
+            player.push_night_message(midnight_variables, ChatMessageVariant::PawnVisitedYou);
+        }
     }
     fn on_role_switch(self, game: &mut Game, actor_ref: PlayerReference, event: &OnRoleSwitch, _fold: &mut (), _priority: ()) {
         if event.old.role() == Role::Pawn {return}
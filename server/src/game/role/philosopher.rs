@@ -15,9 +15,8 @@ impl RoleStateTrait for Philosopher {
     fn on_midnight(self, game: &mut Game, _id: &AbilityID, actor_ref: PlayerReference, midnight_variables: &mut OnMidnightFold, priority: OnMidnightPriority) {
         if priority != OnMidnightPriority::Investigative {return;}
 
-        let mut actor_visits = Visits::into_iter(midnight_variables).default_role_visits(actor_ref, Role::Philosopher);
-        let Some(first_visit) = actor_visits.next() else {return;};
-        let Some(second_visit) = actor_visits.next() else {return;};
+        let actor_visits: Vec<_> = Visits::into_iter(midnight_variables).default_role_visits(actor_ref, Role::Philosopher).collect();
+        let &[first_visit, second_visit, ..] = actor_visits.as_slice() else {return};
 
         let enemies = if Confused::is_confused(game, actor_ref) {
             false
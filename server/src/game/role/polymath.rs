@@ -37,9 +37,8 @@ impl RoleStateTrait for Polymath {
                 }
             },
             (OnMidnightPriority::Warper, PolymathAbilityType::Support) => {
-                let mut targets = Visits::into_iter(midnight_variables).default_targets(actor_ref, Role::Polymath);
-                let Some(from) = targets.next() else {return};
-                let Some(to) = targets.next() else {return};
+                let targets: Vec<_> = Visits::into_iter(midnight_variables).default_targets(actor_ref, Role::Polymath).collect();
+                let &[from, to, ..] = targets.as_slice() else {return};
                 
                 Transport::transport(
                     midnight_variables, TransportPriority::Warper, 
@@ -14,9 +14,8 @@ impl RoleStateTrait for Porter {
     fn on_midnight(self, _game: &mut Game, _id: &AbilityID, actor_ref: PlayerReference, midnight_variables: &mut OnMidnightFold, priority: OnMidnightPriority) {
         if priority != OnMidnightPriority::Warper {return;}
     
-        let mut targets = Visits::into_iter(midnight_variables).default_targets(actor_ref, Role::Porter);
-        let Some(from) = targets.next() else {return};
-        let Some(to) = targets.next() else {return};
+        let targets: Vec<_> = Visits::into_iter(midnight_variables).default_targets(actor_ref, Role::Porter).collect();
+        let &[from, to, ..] = targets.as_slice() else {return};
         
         Transport::transport(
             midnight_variables, TransportPriority::Warper, 
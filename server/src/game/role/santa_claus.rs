@@ -26,7 +26,7 @@ impl RoleStateTrait for SantaClaus {
 
         match self.get_next_santa_ability() {
             SantaListKind::Nice => {
-                for target_ref in Visits::into_iter(midnight_variables).default_targets(actor_ref, Role::SantaClaus) {
+                for target_ref in Visits::into_iter(midnight_variables).default_targets(actor_ref, Role::SantaClaus).collect::<Vec<_>>() {
                     let WinCondition::GameConclusionReached { mut win_if_any } = target_ref.win_condition(game).clone() else {
                         actor_ref.push_night_message(midnight_variables, ChatMessageVariant::YourConvertFailed);
                         continue
@@ -50,7 +50,7 @@ impl RoleStateTrait for SantaClaus {
                 }
             }
             SantaListKind::Naughty => {
-                for target_ref in Visits::into_iter(midnight_variables).default_targets(actor_ref, Role::SantaClaus) {
+                for target_ref in Visits::into_iter(midnight_variables).default_targets(actor_ref, Role::SantaClaus).collect::<Vec<_>>() {
                     let WinCondition::GameConclusionReached { mut win_if_any } = target_ref.win_condition(game).clone() else {
                         actor_ref.push_night_message(midnight_variables, ChatMessageVariant::YourConvertFailed);
                         continue
@@ -1,3 +1,4 @@
+use rand::Rng;
 use serde::Serialize;
 use crate::game::prelude::*;
 use crate::game::role::detective::Detective;
@@ -75,10 +76,10 @@ impl TallyClerk {
             .try_into()
             .unwrap_or(u8::MAX)
     }
-    fn confused_result(game: &Game, midnight_variables: &OnMidnightFold, targets: &[PlayerReference])->u8{
+    fn confused_result(game: &mut Game, midnight_variables: &OnMidnightFold, targets: &[PlayerReference])->u8{
         let total_players = targets.len();
 
-        let evil_count = Self::result(game, midnight_variables, targets).saturating_add_signed(rand::random_range(0..=1));
+        let evil_count = Self::result(game, midnight_variables, targets).saturating_add_signed(game.rng.random_range(0..=1));
         
         evil_count.min(total_players.try_into().unwrap_or(u8::MAX))
     }
@@ -15,9 +15,8 @@ impl RoleStateTrait for Transporter {
     fn on_midnight(self, _game: &mut Game, _id: &AbilityID, actor_ref: PlayerReference, midnight_variables: &mut OnMidnightFold, priority: OnMidnightPriority) {
         if priority != OnMidnightPriority::Transporter {return;}
     
-        let mut targets = Visits::into_iter(midnight_variables).default_targets(actor_ref, Role::Transporter);
-        let Some(a) = targets.next() else {return};
-        let Some(b) = targets.next() else {return};
+        let targets: Vec<_> = Visits::into_iter(midnight_variables).default_targets(actor_ref, Role::Transporter).collect();
+        let &[a, b, ..] = targets.as_slice() else {return};
         
         Transport::transport(
             midnight_variables, TransportPriority::Transporter, 
@@ -13,9 +13,8 @@ impl RoleStateTrait for Warper {
     fn on_midnight(self, game: &mut Game, _id: &AbilityID, actor_ref: PlayerReference, midnight_variables: &mut OnMidnightFold, priority: OnMidnightPriority) {
         if priority != OnMidnightPriority::Warper {return;}
     
-        let mut transporter_visits = Visits::into_iter(midnight_variables).default_targets(actor_ref, Role::Warper);
-        let Some(first_visit) = transporter_visits.next() else {return};
-        let Some(second_visit) = transporter_visits.next() else {return};
+        let transporter_visits: Vec<_> = Visits::into_iter(midnight_variables).default_targets(actor_ref, Role::Warper).collect();
+        let &[first_visit, second_visit, ..] = transporter_visits.as_slice() else {return};
         
         Transport::transport(
             midnight_variables, TransportPriority::Warper, 
@@ -1,8 +1,6 @@
 #![allow(clippy::indexing_slicing, reason = "We ensure the index is valid before accessing it")]
 
-use std::collections::HashMap;
-
-use crate::{game::{components::{ascend::Ascend, insider_group::InsiderGroupID, win_condition::WinCondition}, game_conclusion::{GameConclusion, GameOverCheckPlayer}, player::PlayerReference, role_list::{RoleOutlineOptionInsiderGroups, RoleOutlineOptionWinCondition}, role_list_generation::{PartialOutlineAssignment, PartialOutlineListAssignmentNode}, settings::Settings}, vec_set::VecSet};
+use crate::{game::{components::{ascend::Ascend, insider_group::InsiderGroupID, win_condition::WinCondition}, game_conclusion::{GameConclusion, GameOverCheckPlayer}, player::PlayerReference, role_list::{RoleOutlineOptionInsiderGroups, RoleOutlineOptionWinCondition}, role_list_generation::{PartialOutlineAssignment, PartialOutlineListAssignmentNode}, settings::Settings}, vec_map::VecMap, vec_set::VecSet};
 
 
 #[derive(Clone, Copy)]
@@ -45,18 +43,20 @@ pub const FILL_ALL_ROLES: GenerationCriterion = GenerationCriterion {
 
 pub const REJECT_EXCEEDED_ROLE_LIMITS: GenerationCriterion = GenerationCriterion {
     evaluate: |node, settings| {
-        let mut role_appearances = HashMap::new();
+        // Ordered, so the neighbors are in the same order every time and shuffling them is seeded
+        let mut role_appearances: VecMap<_, u8> = VecMap::new();
 
         for assignment in node.assignments.iter() {
             if let Some(role) = assignment.role {
-                *role_appearances.entry(role).or_insert(0) += 1;
+                let appearances = role_appearances.get(&role).copied().unwrap_or(0);
+                role_appearances.insert(role, appearances.saturating_add(1));
             }
         }
 
         let mut exceeded_roles = vec![];
 
-        for role in role_appearances.keys() {
-            if let Some(max) = role.maximum_count(settings) && role_appearances[role] > max && !exceeded_roles.contains(role) {
+        for (role, appearances) in role_appearances.iter() {
+            if let Some(max) = role.maximum_count(settings) && *appearances > max && !exceeded_roles.contains(role) {
                 exceeded_roles.push(*role);
             }
         }
@@ -4,6 +4,7 @@ use rand::{rngs::SmallRng, seq::SliceRandom};
 
 use crate::{game::{prelude::*, role_list::RoleOutlineOption, role_outline_reference::RoleOutlineReference}, vec_set::VecSet};
 pub mod criteria;
+pub mod probabilities;
 pub use criteria::{
     GenerationCriterion,
     GenerationCriterionResult
@@ -0,0 +1,80 @@
+use rand::{rngs::SmallRng, SeedableRng};
+use serde::Serialize;
+
+use crate::{game::{components::win_condition::WinCondition, game_conclusion::GameConclusion, role::Role, settings::Settings}, vec_map::VecMap, vec_set::VecSet};
+
+use super::RoleListGenerator;
+
+/// How likely each role and faction size is with some settings,
+/// estimated by generating the role list many times with different seeds.
+/// Samples where no role list could be generated don't count towards any probability,
+/// because starting a game retries until one can.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleListProbabilities {
+    pub samples: u16,
+    pub failed_samples: u16,
+    /// The chance of at least one player having each role. Roles that never appeared are left out.
+    pub roles: VecMap<Role, f32>,
+    /// For each conclusion, the chance of exactly `i` players being able to win with it, at index `i`.
+    /// Conclusions nobody could win with are left out.
+    pub faction_sizes: VecMap<GameConclusion, Vec<f32>>,
+}
+
+impl RoleListProbabilities {
+    /// Enough for a percentage to be off by about 3 at most.
+    /// Fifteen outlines of any role take a couple of seconds, which is why this runs off the tick.
+    pub const SAMPLES: u16 = 1000;
+
+    /// Generates the role list with the seeds `0..samples`. The settings' random seed is ignored.
+    pub fn estimate(settings: &Settings, samples: u16) -> Self {
+        let player_count = settings.role_list.0.len();
+        let mut failed_samples: u16 = 0;
+        let mut role_counts: VecMap<Role, u16> = VecMap::new();
+        let mut faction_size_counts: VecMap<GameConclusion, Vec<u16>> = GameConclusion::all()
+            .into_iter()
+            .map(|conclusion| (conclusion, vec![0; player_count.saturating_add(1)]))
+            .collect();
+
+        for seed in 0..samples {
+            let mut rng = SmallRng::seed_from_u64(seed.into());
+            let Some(assignment) = RoleListGenerator::new(settings.clone(), &mut rng).generate_role_list() else {
+                failed_samples = failed_samples.saturating_add(1);
+                continue;
+            };
+
+            let roles: VecSet<Role> = assignment.assignments.iter().map(|assignment| assignment.role).collect();
+            for role in roles {
+                let count = role_counts.get(&role).copied().unwrap_or(0);
+                role_counts.insert(role, count.saturating_add(1));
+            }
+
+            for (conclusion, counts) in faction_size_counts.iter_mut() {
+                let size = assignment.assignments.iter()
+                    .filter(|assignment| match &assignment.win_condition {
+                        WinCondition::GameConclusionReached { win_if_any } => win_if_any.contains(conclusion),
+                        WinCondition::RoleStateWon => false,
+                    })
+                    .count();
+                if let Some(count) = counts.get_mut(size) {
+                    *count = count.saturating_add(1);
+                }
+            }
+        }
+
+        let generated = samples.saturating_sub(failed_samples);
+        let probability = |count: u16| if generated == 0 { 0.0 } else { f32::from(count) / f32::from(generated) };
+
+        let mut roles: VecMap<Role, f32> = role_counts.into_iter()
+            .map(|(role, count)| (role, probability(count)))
+            .collect();
+        roles.sort();
+
+        let faction_sizes = faction_size_counts.into_iter()
+            .filter(|(_, counts)| counts.iter().skip(1).any(|count| *count > 0))
+            .map(|(conclusion, counts)| (conclusion, counts.into_iter().map(probability).collect()))
+            .collect();
+
+        Self { samples, failed_samples, roles, faction_sizes }
+    }
+}
@@ -27,6 +27,7 @@ impl RoleOutlineReference{
     }
 
     pub fn deref<'a>(&self, game: &'a Game)->&'a RoleOutline{
+        debug_assert!((self.index as usize) < game.settings.role_list.0.len(), "role outline {} doesn't exist", self.index);
         unsafe {
             game.settings.role_list.0.get_unchecked(self.index as usize)
         }
@@ -3,6 +3,7 @@ use super::event::on_tick::OnTick;
 use crate::client_connection::ClientConnection;
 use crate::game::chat::ChatGroup;
 use crate::game::chat::ChatMessageVariant;
+use crate::game::components::pause::Pause;
 use crate::game::components::synopsis::SynopsisTracker;
 use crate::game::event::on_game_ending::OnGameEnding;
 use crate::game::event::AsInvokable as _;
@@ -41,10 +42,19 @@ impl RoomState for Game {
                 synopsis: SynopsisTracker::get(self, GameConclusion::Draw)
             });
             self.send_packet_to_all(ToClientPacket::GameOver{ reason: GameOverReason::ReachedMaxDay });
+            self.end_spectator_feed_delay();
             self.ticking = false;
             return RoomTickResult { close_room: !self.is_any_client_connected() };
         }
 
+        if Pause::is_paused(self) {
+            Pause::tick(self, time_passed);
+            // Disconnect timers are frozen too, so nobody is kicked while the game waits for them
+            PlayerReference::all_players(self).for_each(|p|p.send_repeating_data(self));
+            SpectatorPointer::all_spectators(self).for_each(|s|s.send_repeating_data(self));
+            return RoomTickResult { close_room: !self.is_any_client_connected() };
+        }
+
         while self.phase_machine.time_remaining.is_some_and(|d| d.is_zero()) {
             PhaseStateMachine::next_phase(self, None);
         }
@@ -52,6 +62,7 @@ impl RoomState for Game {
         SpectatorPointer::all_spectators(self).for_each(|s|s.tick(self, time_passed));
 
         self.phase_machine.time_remaining = self.phase_machine.time_remaining.map(|d|d.saturating_sub(time_passed));
+        self.phase_machine.time_elapsed = self.phase_machine.time_elapsed.saturating_add(time_passed);
 
         OnTick::new().as_invokable().invoke(self);
 
@@ -70,12 +81,17 @@ impl RoomState for Game {
     }
     
     fn join_client(&mut self, send: &ClientSender) -> Result<JoinRoomClientResult, RejectJoinReason> {
+        if !self.settings.spectators.allow_mid_game_join {
+            return Err(RejectJoinReason::GameAlreadyStarted);
+        }
+
         let is_host = !self.clients.iter().any(|p|p.1.host);
                 
         let Some(room_client_id) = 
             (self.clients
                 .iter()
                 .map(|(i,_)|*i)
+                .chain(self.access.banned_client_ids())
                 .fold(0u32, u32::max) as RoomClientID).checked_add(1) else {
                     return Err(RejectJoinReason::RoomFull);
                 };
@@ -100,7 +116,6 @@ impl RoomState for Game {
             match client.client_location {
                 GameClientLocation::Player(player) => {
                     player.connect(self, send.clone());
-                    player.send_join_game_data(self);
                 },
                 GameClientLocation::Spectator(spectator) => {
                     spectator.send_join_game_data(self);
@@ -127,19 +142,7 @@ impl RoomState for Game {
         match game_player.client_location {
             GameClientLocation::Player(player) => player.quit(self),
             GameClientLocation::Spectator(spectator) => {
-                self.clients.remove(&room_client_id);
-
-                // Shift every other spectator down one index
-                for client in self.clients.iter_mut() {
-                    if 
-                        let GameClientLocation::Spectator(other) = &mut client.1.client_location && 
-                        other.index() > spectator.index()
-                    {
-                        *other = SpectatorPointer::new(other.index().saturating_sub(1));
-                    }
-                }
-
-                self.remove_spectator(spectator.index());
+                self.remove_spectator_client(room_client_id, spectator);
             }
         }
 
@@ -167,19 +170,7 @@ impl RoomState for Game {
                 }
             },
             GameClientLocation::Spectator(spectator) => {
-                self.clients.remove(&id);
-
-                // Shift every other spectator down one index
-                for client in self.clients.iter_mut() {
-                    if 
-                        let GameClientLocation::Spectator(other) = &mut client.1.client_location &&
-                        other.index() > spectator.index()
-                    {
-                        *other = SpectatorPointer::new(other.index().saturating_sub(1));
-                    }
-                }
-
-                self.remove_spectator(spectator.index());
+                self.remove_spectator_client(id, spectator);
             }
         }
 
@@ -216,7 +207,22 @@ impl RoomState for Game {
                         None
                     }
                 )
-                .collect()
+                .collect(),
+            spectators: self.clients.values()
+                .filter(|client| matches!(client.client_location, GameClientLocation::Spectator(_)))
+                .count(),
+            host_name: self.clients.values()
+                .find(|client| client.host)
+                .and_then(|client| if let GameClientLocation::Player(player) = client.client_location {
+                    Some(player.name(self).clone())
+                } else {
+                    None
+                }),
+            phase: Some((self.current_phase().phase(), self.day_number())),
+            role_list_size: self.settings.role_list.0.len(),
+            modifiers: self.settings.modifiers.enabled_modifiers().collect(),
+            spectators_allowed: self.settings.spectators.allow_mid_game_join,
+            password_protected: self.access.password_protected(),
         }
     }
     
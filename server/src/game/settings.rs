@@ -2,9 +2,9 @@ use std::time::Duration;
 
 use serde::{Serialize, Deserialize};
 
-use crate::{game::modifiers::{ModifierSettings}, vec_set::VecSet};
+use crate::{game::modifiers::{ModifierID, ModifierSettings}, vec_map::VecMap, vec_set::VecSet};
 
-use super::{phase::PhaseType, role::Role, role_list::RoleList};
+use super::{phase::PhaseType, phase_schedule::{PhaseSchedule, ScheduledPhase}, role::Role, role_list::RoleList};
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -14,6 +14,108 @@ pub struct Settings{
     pub phase_times: PhaseTimeSettings,
     pub enabled_roles: VecSet<Role>,
     pub modifiers: ModifierSettings,
+    pub spectators: SpectatorSettings,
+    pub trials: TrialSettings,
+    pub phase_schedule: PhaseSchedule,
+    pub inactivity: InactivitySettings,
+}
+impl Settings {
+    /// The phase schedule a game with these settings follows, which is the host's one changed by any modifiers.
+    /// The game still has to check it's valid, a long first day might not fit the phases day start adds.
+    pub fn game_phase_schedule(&self) -> PhaseSchedule {
+        let mut schedule = self.phase_schedule.clone();
+        if
+            self.modifiers.is_enabled(ModifierID::DayStart) &&
+            schedule.first_day.first() != Some(&ScheduledPhase::Discussion)
+        {
+            schedule.first_day.splice(0..0, [ScheduledPhase::Discussion, ScheduledPhase::Trials]);
+        }
+        schedule
+    }
+}
+/// How many times the town may put someone on trial each day.
+/// A single trial per day still gives the accused their testimony as a defense.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrialSettings{
+    /// Trials on the first day with a discussion
+    pub trials_per_day: u8,
+    /// How many fewer trials there are each following day
+    pub decay_per_day: u8,
+    /// Decay never takes the trials below this
+    pub minimum_trials: u8,
+    /// If false, a nomination without a trial goes straight to the next nomination instead of an adjournment
+    pub adjournment: bool,
+}
+impl TrialSettings {
+    pub fn trials_on_day(&self, day_number: u8) -> u8 {
+        let days_of_decay = day_number.saturating_sub(2);
+        self.trials_per_day
+            .saturating_sub(self.decay_per_day.saturating_mul(days_of_decay))
+            .max(self.minimum_trials.min(self.trials_per_day))
+    }
+}
+impl Default for TrialSettings{
+    fn default() -> Self {
+        Self{
+            trials_per_day: 3,
+            decay_per_day: 0,
+            minimum_trials: 0,
+            adjournment: true,
+        }
+    }
+}
+/// What happens to living players who stop doing anything.
+/// Idle phases are discussions the player didn't take part in, with nothing done since the last discussion either.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InactivitySettings{
+    /// Idle phases before a player is warned, 0 turns inactivity tracking off
+    pub warning_after: u8,
+    /// Idle phases before the action is taken
+    pub action_after: u8,
+    pub action: InactivityAction,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InactivityAction{
+    /// Only warn the player
+    None,
+    /// Give the seat to a spectator who is waiting to replace someone
+    Replace,
+    /// Kill the player as if they had quit
+    Kill,
+}
+impl Default for InactivitySettings{
+    fn default() -> Self {
+        Self{
+            warning_after: 0,
+            action_after: 3,
+            action: InactivityAction::None,
+        }
+    }
+}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpectatorSettings{
+    /// If false, new clients can't join as spectators once the game has started
+    pub allow_mid_game_join: bool,
+    /// How many phases behind the game spectators are
+    pub feed_delay: u8,
+    /// Spectators can see everyone's role
+    pub omniscient: bool,
+}
+impl SpectatorSettings {
+    pub const MAX_FEED_DELAY: u8 = 20;
+}
+impl Default for SpectatorSettings{
+    fn default() -> Self {
+        Self{
+            allow_mid_game_join: true,
+            feed_delay: 0,
+            omniscient: false,
+        }
+    }
 }
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -28,8 +130,57 @@ pub struct PhaseTimeSettings{
     pub adjournment: u16,
     pub dusk: u16,
     pub night: u16,
+    #[serde(default)]
+    pub endgame: EndgameTimeSettings,
+    /// Percentage of the normal phase time for each day, starting on day 1.
+    /// Days after the end of the list use the last entry. An empty list leaves every day unchanged.
+    #[serde(default)]
+    pub day_percentages: Vec<u16>,
+}
+/// Changes phase times once few enough players are alive
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndgameTimeSettings{
+    /// Applies once this many players or fewer are alive
+    pub alive_threshold: u8,
+    /// Percentage of the normal phase time, for phases without their own percentage
+    pub percentage: u16,
+    pub phase_percentages: VecMap<PhaseType, u16>,
+}
+impl Default for EndgameTimeSettings{
+    fn default() -> Self {
+        Self{
+            alive_threshold: 3,
+            percentage: 50,
+            phase_percentages: VecMap::new(),
+        }
+    }
 }
 impl PhaseTimeSettings {
+    /// Day and endgame percentages outside this range are rejected, and clamped if they get through anyway
+    pub const MIN_PERCENTAGE: u16 = 1;
+    pub const MAX_PERCENTAGE: u16 = 1000;
+    /// A scaled phase is never longer than the longest time a phase can be set to
+    pub const MAX_SCALED_TIME: Duration = Duration::from_secs(u16::MAX as u64);
+
+    /// The phase time after day scaling and the endgame rules are applied
+    pub fn get_scaled_time_for(&self, phase: PhaseType, day_number: u8, alive_players: usize) -> Option<Duration> {
+        let mut time = self.get_time_for(phase)?;
+
+        if let Some(percentage) = self.day_percentages
+            .get(usize::from(day_number.saturating_sub(1)))
+            .or(self.day_percentages.last())
+        {
+            time = scale_by_percentage(time, *percentage);
+        }
+
+        if alive_players <= usize::from(self.endgame.alive_threshold) {
+            let percentage = self.endgame.phase_percentages.get(&phase).copied().unwrap_or(self.endgame.percentage);
+            time = scale_by_percentage(time, percentage);
+        }
+
+        Some(time.min(Self::MAX_SCALED_TIME))
+    }
     pub fn get_time_for(&self, phase: PhaseType) -> Option<Duration> {
         match phase {
             PhaseType::Briefing => Some(Duration::from_secs(self.briefing as u64)),
@@ -45,8 +196,24 @@ impl PhaseTimeSettings {
             PhaseType::Recess => None,
         }
     }
+    /// True if on some day, with or without the endgame rules, every phase that always happens would take no time
     pub fn game_ends_instantly(&self)->bool{
-        [self.obituary, self.discussion, self.nomination, self.night, self.dusk].iter().all(|t| *t == 0)
+        let last_scaled_day = u8::try_from(self.day_percentages.len()).unwrap_or(u8::MAX).max(1);
+
+        (1..=last_scaled_day).any(|day_number|
+            [usize::MAX, 0].into_iter().any(|alive_players|
+                [PhaseType::Obituary, PhaseType::Discussion, PhaseType::Nomination, PhaseType::Night, PhaseType::Dusk]
+                    .into_iter()
+                    .all(|phase| self.get_scaled_time_for(phase, day_number, alive_players).is_none_or(|time| time.is_zero()))
+            )
+        )
+    }
+    pub fn percentages_are_valid(&self) -> bool {
+        self.day_percentages.len() <= usize::from(u8::MAX) &&
+        self.day_percentages.iter()
+            .chain(std::iter::once(&self.endgame.percentage))
+            .chain(self.endgame.phase_percentages.values())
+            .all(|percentage| (Self::MIN_PERCENTAGE..=Self::MAX_PERCENTAGE).contains(percentage))
     }
 }
 impl Default for PhaseTimeSettings{
@@ -62,6 +229,14 @@ impl Default for PhaseTimeSettings{
             final_words:10,
             dusk:30,
             night:60,
+            endgame: EndgameTimeSettings::default(),
+            day_percentages: Vec::new(),
         }
     }
-}
\ No newline at end of file
+}
+fn scale_by_percentage(time: Duration, percentage: u16) -> Duration {
+    let percentage = percentage.clamp(PhaseTimeSettings::MIN_PERCENTAGE, PhaseTimeSettings::MAX_PERCENTAGE);
+    time.checked_mul(u32::from(percentage))
+        .and_then(|time| time.checked_div(100))
+        .unwrap_or(time)
+}
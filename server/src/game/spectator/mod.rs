@@ -1,3 +1,4 @@
+pub mod spectator_feed;
 pub mod spectator_pointer;
 
 use std::collections::VecDeque;
@@ -15,6 +16,8 @@ pub struct SpectatorInitializeParameters {
 pub struct Spectator {
     pub connection: ClientConnection,
     pub fast_forward_vote: bool,
+    /// Wants to take the seat of a player removed for inactivity
+    pub waiting_to_replace: bool,
 
     pub queued_chat_messages: VecDeque<(ChatMessageIndex, ChatMessageVariant)>,
 }
@@ -23,6 +26,7 @@ impl Spectator {
         Self {
             connection: params.connection,
             fast_forward_vote: false,
+            waiting_to_replace: false,
 
             queued_chat_messages: VecDeque::new(),
         }
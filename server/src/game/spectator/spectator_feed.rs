@@ -0,0 +1,98 @@
+use std::collections::VecDeque;
+
+use crate::{game::chat::ChatMessageIndex, packet::ToClientPacket};
+
+#[derive(Debug, Clone)]
+pub enum SpectatorFeedEntry {
+    Packet(ToClientPacket),
+    ChatMessage(ChatMessageIndex),
+}
+
+/// Holds back what spectators see by a number of phases,
+/// so a spectator can't relay information to players while it still matters.
+///
+/// Entries are dropped once released, but what they showed is kept
+/// so spectators who join late can be caught up to the same point without seeing anything newer.
+/// If there is no delay, nothing is stored and entries should be sent straight to spectators.
+pub struct SpectatorFeed {
+    delay: u8,
+    phases_started: u16,
+    /// Entries spectators haven't received yet, with the phase they were pushed in
+    held: VecDeque<(u16, SpectatorFeedEntry)>,
+    /// The newest released packet of each type, except graves which are all kept
+    released_packets: Vec<ToClientPacket>,
+    /// Spectator chat messages before this index have been released
+    released_chat_messages: ChatMessageIndex,
+}
+
+impl SpectatorFeed {
+    pub fn new(delay: u8) -> Self {
+        Self {
+            delay,
+            phases_started: 0,
+            held: VecDeque::new(),
+            released_packets: Vec::new(),
+            released_chat_messages: 0,
+        }
+    }
+
+    /// What spectators see before anything is released
+    pub fn set_game_start(&mut self, packets: Vec<ToClientPacket>) {
+        if self.is_delayed() {
+            self.released_packets = packets;
+        }
+    }
+
+    pub fn is_delayed(&self) -> bool {
+        self.delay > 0
+    }
+
+    pub fn push(&mut self, entry: SpectatorFeedEntry) {
+        self.held.push_back((self.phases_started, entry));
+    }
+
+    /// Returns the entries that spectators should now receive
+    pub fn on_phase_start(&mut self) -> Vec<SpectatorFeedEntry> {
+        self.phases_started = self.phases_started.saturating_add(1);
+
+        let mut released = Vec::new();
+        while let Some((phase, _)) = self.held.front()
+            && phase.saturating_add(self.delay.into()) <= self.phases_started
+            && let Some((_, entry)) = self.held.pop_front()
+        {
+            self.remember(&entry);
+            released.push(entry);
+        }
+        released
+    }
+
+    /// Stops delaying the feed, returning every entry spectators haven't received yet
+    pub fn release_all(&mut self) -> Vec<SpectatorFeedEntry> {
+        self.delay = 0;
+        self.released_packets.clear();
+        self.held.drain(..).map(|(_, entry)| entry).collect()
+    }
+
+    fn remember(&mut self, entry: &SpectatorFeedEntry) {
+        match entry {
+            SpectatorFeedEntry::Packet(packet) => {
+                if !matches!(packet, ToClientPacket::AddGrave { .. }) {
+                    self.released_packets.retain(|released| std::mem::discriminant(released) != std::mem::discriminant(packet));
+                }
+                self.released_packets.push(packet.clone());
+            }
+            SpectatorFeedEntry::ChatMessage(index) => {
+                self.released_chat_messages = self.released_chat_messages.max(index.saturating_add(1));
+            }
+        }
+    }
+
+    /// Enough to show a spectator everything released so far
+    pub fn released_packets(&self) -> &[ToClientPacket] {
+        &self.released_packets
+    }
+
+    pub fn released_chat_messages(&self) -> ChatMessageIndex {
+        self.released_chat_messages
+    }
+}
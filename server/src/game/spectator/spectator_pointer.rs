@@ -3,7 +3,7 @@ use std::time::Duration;
 use serde::Serialize;
 
 use crate::{
-    client_connection::ClientConnection, game::{chat::{ChatGroup, ChatMessage}, components::graves::grave_reference::GraveReference, player::PlayerReference, Game, GameOverReason}, packet::ToClientPacket
+    client_connection::ClientConnection, game::{chat::{ChatGroup, ChatMessage}, components::{graves::grave_reference::GraveReference, pause::Pause}, player::PlayerReference, Game, GameOverReason}, packet::ToClientPacket
 };
 
 use super::Spectator;
@@ -74,45 +74,84 @@ impl SpectatorPointer {
         self.send_chat_messages(game);
     }
     pub fn send_join_game_data(&self, game: &mut Game){
-        // General
+        // Settings, which don't change during the game
         self.send_packets(game, vec![
-            ToClientPacket::GamePlayers{ 
-                players: PlayerReference::all_players(game).map(|p|p.name(game).clone()).collect()
-            },
             ToClientPacket::EnabledRoles { roles: game.settings.enabled_roles.clone().into_iter().collect() },
             ToClientPacket::RoleList {role_list: game.settings.role_list.clone()},
             ToClientPacket::ModifierSettings {
                 modifier_settings: game.modifier_settings().clone()
             },
+        ]);
+
+        if game.spectator_feed.is_delayed() {
+            self.send_released_feed(game);
+        } else {
+            self.send_packets(game, Self::game_start_packets(game));
+
+            if !game.ticking {
+                self.send_packet(game, ToClientPacket::GameOver { reason: GameOverReason::Draw })
+            }
+
+            game.send_player_votes();
+            for grave in GraveReference::all_graves(game){
+                self.send_packet(game, ToClientPacket::AddGrave { grave: grave.deref(game).clone(), grave_ref: grave });
+            }
+
+            self.send_packets(game, vec![
+                ToClientPacket::Phase { 
+                    phase: game.current_phase().clone(),
+                    day_number: game.phase_machine.day_number 
+                },
+                ToClientPacket::PhaseTimeLeft { seconds_left: game.phase_machine.time_remaining.map(|o|o.as_secs().try_into().expect("Phase time should be below 18 hours")) },
+                Pause::packet(game)
+            ]);
+            if game.settings.spectators.omniscient {
+                self.send_packet(game, Self::role_labels_packet(game));
+            }
+
+            self.requeue_chat_messages(game);
+        }
+        self.send_chat_messages(game);
+
+        self.send_packet(game, ToClientPacket::GameInitializationComplete);
+    }
+
+    /// The players and phase times, which a delayed feed shows as they were when the game started
+    pub fn game_start_packets(game: &Game) -> Vec<ToClientPacket> {
+        vec![
+            ToClientPacket::GamePlayers{ 
+                players: PlayerReference::all_players(game).map(|p|p.name(game).clone()).collect()
+            },
             ToClientPacket::PlayerAlive{
                 alive: PlayerReference::all_players(game).map(|p|p.alive(game)).collect()
             },
             ToClientPacket::PhaseTimes {
                 phase_time_settings: game.settings.phase_times.clone()
             }
-        ]);
+        ]
+    }
 
-        if !game.ticking {
-            self.send_packet(game, ToClientPacket::GameOver { reason: GameOverReason::Draw })
-        }
+    /// Catches a spectator up on everything the delayed feed has let through so far
+    fn send_released_feed(&self, game: &mut Game){
+        self.send_packets(game, game.spectator_feed.released_packets().to_vec());
 
-        game.send_player_votes();
-        for grave in GraveReference::all_graves(game){
-            self.send_packet(game, ToClientPacket::AddGrave { grave: grave.deref(game).clone(), grave_ref: grave });
+        let released_chat_messages = game.spectator_feed.released_chat_messages();
+        let messages: Vec<_> = game.spectator_chat_messages.iter().take(released_chat_messages).cloned().enumerate().collect();
+        if let Some(s) = self.deref_mut(game) {
+            s.queued_chat_messages.extend(messages);
         }
+    }
 
-        self.send_packets(game, vec![
-            ToClientPacket::Phase { 
-                phase: game.current_phase().clone(),
-                day_number: game.phase_machine.day_number 
-            },
-            ToClientPacket::PhaseTimeLeft { seconds_left: game.phase_machine.time_remaining.map(|o|o.as_secs().try_into().expect("Phase time should be below 18 hours")) }
-        ]);
-
-        self.requeue_chat_messages(game);
-        self.send_chat_messages(game);
-
-        self.send_packet(game, ToClientPacket::GameInitializationComplete);
+    fn role_labels_packet(game: &Game) -> ToClientPacket {
+        ToClientPacket::YourRoleLabels {
+            role_labels: PlayerReference::all_players(game).map(|p| (p, p.role(game))).collect()
+        }
+    }
+    /// Roles are resent every phase so omniscient spectators see role changes
+    pub fn send_omniscient_role_labels(game: &mut Game){
+        if game.settings.spectators.omniscient {
+            game.send_packet_to_spectators(Self::role_labels_packet(game));
+        }
     }
 
     pub fn requeue_chat_messages(&self, game: &mut Game){
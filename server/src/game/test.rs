@@ -3,7 +3,7 @@ use rand::{rngs::SmallRng, SeedableRng as _};
 use crate::{
     game::{
         abilities_component::Abilities, chat::{ChatComponent, PlayerChatGroups}, components::{blocked::BlockedComponent, enfranchise::EnfranchiseComponent, fast_forward::FastForwardComponent, graves::Graves, hide_votes_message::HideVotesMessage, pitchfork_item::PitchforkItemComponent, role::RoleComponent, role_reveal::RevealedPlayersComponent}, event::{AsInvokable as _, Invokable as _}, role_list_generation::RoleListGenerator
-    }, vec_map::VecMap
+    }, lobby::lobby_chat::LobbyChat, room::room_access::RoomAccess, vec_map::VecMap
 };
 
 use super::{
@@ -11,10 +11,10 @@ use super::{
         cult::Cult, fragile_vest::FragileVests, insider_group::InsiderGroups,
         mafia::Mafia, mafia_recruits::MafiaRecruits, player_component::PlayerComponent,
         poison::Poison, puppeteer_marionette::PuppeteerMarionette, silenced::Silenced,
-        synopsis::SynopsisTracker, tags::Tags, verdicts_today::VerdictsToday, win_condition::WinCondition
-    }, event::on_game_start::OnGameStart,
+        synopsis::SynopsisTracker, tags::Tags, verdicts_today::VerdictsToday, nomination_revote::NominationRevote, pause::Pause, moderation::Moderation, inactivity::Inactivity, vote_history::VoteHistory, night_trace::NightTrace, win_condition::WinCondition
+    }, event::{on_game_start::OnGameStart, registry::EventRegistry},
     phase::PhaseStateMachine, player::{test::mock_player, PlayerReference},
-    settings::Settings, Assignments, Game, RejectStartReason
+    settings::Settings, spectator::{spectator_feed::SpectatorFeed, spectator_pointer::SpectatorPointer}, Assignments, Game, RejectStartReason
 };
 
 pub fn mock_game(settings: Settings, num_players: u8) -> Result<(Game, Assignments), RejectStartReason> {
@@ -23,6 +23,13 @@ pub fn mock_game(settings: Settings, num_players: u8) -> Result<(Game, Assignmen
     if settings.phase_times.game_ends_instantly() {
         return Err(RejectStartReason::ZeroTimeGame);
     }
+    let phase_schedule = settings.game_phase_schedule();
+    if !phase_schedule.is_valid() {
+        return Err(RejectStartReason::InvalidPhaseSchedule);
+    }
+    if !settings.phase_times.percentages_are_valid() {
+        return Err(RejectStartReason::InvalidPhaseTimes);
+    }
     let mut rng = SmallRng::seed_from_u64(settings.random_seed.unwrap_or_else(rand::random));
 
     let settings = settings.clone();
@@ -47,15 +54,19 @@ pub fn mock_game(settings: Settings, num_players: u8) -> Result<(Game, Assignmen
     let mut game = Game{
         clients: VecMap::new(),
         room_name: "Test".to_string(),
+        lobby_chat: LobbyChat::new(),
+        access: RoomAccess::new(),
         
         assignments: assignments.clone(),
         ticking: true,
         rng: rng.clone(),
         spectators: Vec::new(),
         spectator_chat_messages: Vec::new(),
+        spectator_feed: SpectatorFeed::new(settings.spectators.feed_delay),
         players: players.into_boxed_slice(),
-        phase_machine: PhaseStateMachine::new(settings.phase_times.clone()),
+        phase_machine: PhaseStateMachine::new(settings.phase_times.clone(), phase_schedule),
         abilities: Abilities::new(&assignments, &settings.enabled_roles),
+        event_registry: EventRegistry::new(&settings),
         settings,
 
         player_chat_groups: PlayerChatGroups::new(),
@@ -68,6 +79,12 @@ pub fn mock_game(settings: Settings, num_players: u8) -> Result<(Game, Assignmen
         puppeteer_marionette: PuppeteerMarionette::default(),
         mafia_recruits: MafiaRecruits::default(),
         verdicts_today: VerdictsToday::default(),
+        nomination_revote: NominationRevote::default(),
+        pause: Pause::default(),
+        moderation: Moderation::default(),
+        inactivity: Inactivity::default(),
+        vote_history: VoteHistory::default(),
+        night_trace: NightTrace::default(),
         blocked: unsafe {BlockedComponent::new(num_players)},
         poison: Poison::default(),
         insider_groups: unsafe{InsiderGroups::new(num_players, &assignments)},
@@ -94,6 +111,8 @@ pub fn mock_game(settings: Settings, num_players: u8) -> Result<(Game, Assignmen
         InsiderGroups::send_player_insider_groups_packet(&game, player);
     }
     
+    let game_start = SpectatorPointer::game_start_packets(&game);
+    game.spectator_feed.set_game_start(game_start);
     Abilities::set_abilities_states_to_default(&mut game);
 
     //on role creation needs to be called after all players roles are known
@@ -0,0 +1,114 @@
+use std::collections::VecDeque;
+
+use crate::{game::chat::{ChatGroup, ChatMessage, ChatMessageIndex, ChatMessageVariant}, strings::TidyableString, vec_map::VecMap};
+
+/// The lobby's chat room.
+/// Keeps a bounded history so clients joining later can catch up,
+/// and the word filter the host has set for this room.
+#[derive(Clone, Debug, Default)]
+pub struct LobbyChat {
+    history: VecDeque<(ChatMessageIndex, ChatMessage)>,
+    next_index: ChatMessageIndex,
+    word_filter: Vec<String>,
+}
+
+impl LobbyChat {
+    const MAX_HISTORY_LENGTH: usize = 100;
+    const MAX_FILTERED_WORDS: usize = 100;
+    const MAX_FILTERED_WORD_LENGTH: usize = 30;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Filters the message, stores it in the history, and returns what should be broadcast
+    pub fn add_message(&mut self, sender: String, text: String) -> (ChatMessageIndex, ChatMessage) {
+        let index = self.next_index;
+        self.next_index = self.next_index.saturating_add(1);
+
+        let message = ChatMessage::new_non_private(
+            ChatMessageVariant::LobbyMessage { sender, text: self.filter(&text) },
+            ChatGroup::All
+        );
+
+        self.history.push_back((index, message.clone()));
+        while self.history.len() > Self::MAX_HISTORY_LENGTH {
+            self.history.pop_front();
+        }
+
+        (index, message)
+    }
+
+    /// Returns true if the message was still in the history
+    pub fn delete_message(&mut self, index: ChatMessageIndex) -> bool {
+        let length_before = self.history.len();
+        self.history.retain(|(i, _)| *i != index);
+        self.history.len() != length_before
+    }
+
+    pub fn history(&self) -> VecMap<ChatMessageIndex, ChatMessage> {
+        self.history.iter().cloned().collect()
+    }
+
+    pub fn word_filter(&self) -> &Vec<String> {
+        &self.word_filter
+    }
+
+    pub fn set_word_filter(&mut self, words: Vec<String>) {
+        self.word_filter = words.into_iter()
+            .map(|word| word
+                .remove_newline()
+                .trim_whitespace()
+                .truncate(Self::MAX_FILTERED_WORD_LENGTH)
+                .chars()
+                .map(lowercase_char)
+                .collect::<String>()
+            )
+            .filter(|word| !word.is_empty())
+            .take(Self::MAX_FILTERED_WORDS)
+            .collect();
+        self.word_filter.sort();
+        self.word_filter.dedup();
+    }
+
+    /// Replaces every filtered word in the text with asterisks. Matching is case insensitive,
+    /// and only whole words match, so a filtered word inside a longer word is left alone.
+    fn filter(&self, text: &str) -> String {
+        let mut chars: Vec<char> = text.chars().collect();
+        let lowercase: Vec<char> = chars.iter().map(|c| lowercase_char(*c)).collect();
+
+        for word in self.word_filter.iter() {
+            let word: Vec<char> = word.chars().collect();
+            if word.is_empty() {continue}
+
+            let mut start: usize = 0;
+            while let Some(window) = lowercase.get(start..start.saturating_add(word.len())) {
+                let end = start.saturating_add(word.len());
+                let letter_before = start.checked_sub(1)
+                    .and_then(|before| lowercase.get(before))
+                    .is_some_and(|c| c.is_alphanumeric());
+                let letter_after = lowercase.get(end).is_some_and(|c| c.is_alphanumeric());
+                let whole_word = !letter_before && !letter_after;
+                if window == word.as_slice() && whole_word {
+                    for c in chars.iter_mut().skip(start).take(word.len()) {
+                        *c = '*';
+                    }
+                    start = start.saturating_add(word.len());
+                } else {
+                    start = start.saturating_add(1);
+                }
+            }
+        }
+
+        chars.into_iter().collect()
+    }
+}
+
+/// Lowercases a character without changing the number of characters in the string
+fn lowercase_char(c: char) -> char {
+    let mut lowercase = c.to_lowercase();
+    match (lowercase.next(), lowercase.next()) {
+        (Some(lower), None) => lower,
+        _ => c
+    }
+}
@@ -13,6 +13,7 @@ pub struct LobbyClient{
     pub connection: ClientConnection,
     pub ready: Ready,
     pub client_type: LobbyClientType,
+    pub muted: bool,
     
     #[serde(skip)]
     pub last_message_times: VecDeque<Instant>,
@@ -42,6 +43,7 @@ impl LobbyClient {
             connection: ClientConnection::Connected(connection),
             ready: if host { Ready::Host } else { Ready::NotReady },
             client_type: LobbyClientType::Player{name},
+            muted: false,
             last_message_times: VecDeque::new()
         }
     }
@@ -53,6 +55,7 @@ impl LobbyClient {
                     connection: player.connection(game).clone(),
                     ready: if game_client.host { Ready::Host } else { Ready::NotReady },
                     client_type: LobbyClientType::Player{name: player.name(game).to_string()},
+                    muted: game_client.muted,
                     last_message_times: VecDeque::new()
                 }
             },
@@ -61,6 +64,7 @@ impl LobbyClient {
                     connection: spectator.connection(game),
                     ready: if game_client.host { Ready::Host } else { Ready::Ready },
                     client_type: LobbyClientType::Spectator,
+                    muted: game_client.muted,
                     last_message_times: VecDeque::new()
                 }
             }
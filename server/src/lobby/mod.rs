@@ -1,29 +1,58 @@
 pub mod lobby_client;
+pub mod lobby_chat;
 pub mod on_client_message;
 
-use std::{collections::VecDeque, time::{Duration, Instant}};
+use std::{collections::VecDeque, sync::{atomic::{AtomicBool, Ordering}, Arc}, time::{Duration, Instant}};
 
+use lobby_chat::LobbyChat;
+use rand::{rngs::SmallRng, SeedableRng};
 use lobby_client::{LobbyClient, LobbyClientType, Ready};
 
-use crate::{client_connection::ClientConnection, game::{role_list::RoleOutline, settings::Settings}, packet::{RejectJoinReason, RoomPreviewData, ToClientPacket}, room::{name_validation, JoinRoomClientResult, RemoveRoomClientResult, RoomClientID, RoomState, RoomTickResult}, vec_map::VecMap, websocket_connections::connection::ClientSender};
+use crate::{client_connection::ClientConnection, game::{role_list::RoleOutline, settings::Settings}, packet::{RejectJoinReason, RoomPreviewData, ToClientPacket}, room::{name_validation, room_access::RoomAccess, JoinRoomClientResult, RemoveRoomClientResult, RoomClientID, RoomState, RoomTickResult}, vec_map::VecMap, websocket_connections::connection::ClientSender};
 
 pub struct Lobby {
     pub name: String,
     pub settings: Settings,
     pub clients: VecMap<RoomClientID, LobbyClient>,
 
-    pub chat_message_index: usize
+    pub chat: LobbyChat,
+    pub access: RoomAccess,
+    /// For random names. Games are seeded separately, from their settings.
+    pub rng: SmallRng,
+    /// Whether role list probabilities are being estimated on another thread, so a host can't start more than one
+    pub estimating_probabilities: Arc<AtomicBool>,
+}
+
+/// Held while role list probabilities are estimated.
+/// Dropping it lets the host ask again, even if the estimate panicked.
+struct EstimatingProbabilities(Arc<AtomicBool>);
+impl EstimatingProbabilities {
+    /// None if an estimate is already running
+    fn start(estimating: &Arc<AtomicBool>) -> Option<Self> {
+        (!estimating.swap(true, Ordering::AcqRel)).then(|| Self(estimating.clone()))
+    }
+}
+impl Drop for EstimatingProbabilities {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
 }
 
 impl Lobby {
     const DISCONNECT_TIMER_SECS: u64 = 5;
 
     pub fn new() -> Self {
+        Self::with_rng(SmallRng::from_os_rng())
+    }
+    pub fn with_rng(rng: SmallRng) -> Self {
         Self {
             name: name_validation::DEFAULT_SERVER_NAME.to_string(),
             settings: Settings::default(),
             clients: VecMap::new(),
-            chat_message_index: 0,
+            chat: LobbyChat::new(),
+            access: RoomAccess::new(),
+            rng,
+            estimating_probabilities: Arc::default(),
         }
     }
 
@@ -75,6 +104,12 @@ impl Lobby {
         send.send(ToClientPacket::RoleList { role_list: self.settings.role_list.clone() });
         send.send(ToClientPacket::EnabledRoles { roles: self.settings.enabled_roles.clone().into_iter().collect() });
         send.send(ToClientPacket::ModifierSettings { modifier_settings: self.settings.modifiers.clone() });
+        send.send(ToClientPacket::SpectatorSettings { spectator_settings: self.settings.spectators.clone() });
+        send.send(ToClientPacket::TrialSettings { trial_settings: self.settings.trials.clone() });
+        send.send(ToClientPacket::InactivitySettings { inactivity_settings: self.settings.inactivity.clone() });
+        send.send(ToClientPacket::PhaseSchedule { phase_schedule: self.settings.phase_schedule.clone() });
+        send.send(ToClientPacket::LobbyWordFilter { words: self.chat.word_filter().clone() });
+        send.send(ToClientPacket::RoomAccess { unlisted: self.access.unlisted, password_protected: self.access.password_protected() });
     }
 
     pub fn set_player_name(&mut self, room_client_id: RoomClientID, name: String) {
@@ -91,7 +126,7 @@ impl Lobby {
             }).collect::<Vec<_>>()
         };
         
-        let new_name: String = name_validation::sanitize_name(name, &other_player_names);
+        let new_name: String = name_validation::sanitize_name(name, &other_player_names, &mut self.rng);
 
         if 
             let Some(player) = self.clients.get_mut(&room_client_id) &&
@@ -125,13 +160,14 @@ impl Lobby {
         }
     }
     
-    pub fn new_from_game(name: String, settings: Settings, clients: VecMap<RoomClientID, LobbyClient>) -> Self {
-        let new = Self { name, settings, clients, chat_message_index: 0 };
+    pub fn new_from_game(name: String, settings: Settings, clients: VecMap<RoomClientID, LobbyClient>, chat: LobbyChat, access: RoomAccess, rng: SmallRng) -> Self {
+        let new = Self { name, settings, clients, chat, access, rng, estimating_probabilities: Arc::default() };
 
         for (id, client) in new.clients.iter() {
             client.send(ToClientPacket::YourId { player_id: *id });
             if let ClientConnection::Connected(send) = &client.connection {
                 new.send_settings(send);
+                send.send(ToClientPacket::AddChatMessages { chat_messages: new.chat.history() });
             }
         }
 
@@ -157,13 +193,14 @@ impl RoomState for Lobby {
             }
         }).collect::<Vec<_>>();
 
-        let name = name_validation::sanitize_name("".to_string(), &player_names);
+        let name = name_validation::sanitize_name("".to_string(), &player_names, &mut self.rng);
         
         let new_player = LobbyClient::new(name.clone(), send.clone(), self.clients.is_empty());
         let Some(room_client_id) =
             (self.clients
                 .iter()
                 .map(|(i,_)|*i)
+                .chain(self.access.banned_client_ids())
                 .fold(0u32, u32::max) as RoomClientID).checked_add(1) else {
                     return Err(RejectJoinReason::RoomFull)
                 };
@@ -247,6 +284,7 @@ impl RoomState for Lobby {
     fn initialize_client(&mut self, _room_client_id: RoomClientID, send: &ClientSender) {
         self.send_players();
         self.send_settings(send);
+        send.send(ToClientPacket::AddChatMessages { chat_messages: self.chat.history() });
         
         send.send(ToClientPacket::RoomName { name: self.name.clone() });
     }
@@ -277,16 +315,27 @@ impl RoomState for Lobby {
     }
     
     fn get_preview_data(&self) -> RoomPreviewData {
+        let name_of = |client: &LobbyClient| if let LobbyClientType::Player { name } = &client.client_type {
+            Some(name.clone())
+        } else {
+            None
+        };
+
         RoomPreviewData { 
             name: self.name.clone(),
             in_game: false,
-            players: self.clients.iter().filter_map(|p|
-                if let LobbyClientType::Player { name } = &p.1.client_type {
-                    Some((*p.0, name.clone()))
-                }else{
-                    None
-                }
-            ).collect()
+            players: self.clients.iter()
+                .filter_map(|(id, client)| name_of(client).map(|name| (*id, name)))
+                .collect(),
+            spectators: self.clients.values()
+                .filter(|client| matches!(client.client_type, LobbyClientType::Spectator))
+                .count(),
+            host_name: self.clients.values().find(|client| client.is_host()).and_then(name_of),
+            phase: None,
+            role_list_size: self.settings.role_list.0.len(),
+            modifiers: self.settings.modifiers.enabled_modifiers().collect(),
+            spectators_allowed: self.settings.spectators.allow_mid_game_join,
+            password_protected: self.access.password_protected(),
         }
     }
     
@@ -2,10 +2,9 @@ use std::collections::VecDeque;
 
 use crate::{
     game::{
-        chat::{ChatMessage, ChatMessageVariant},
         game_client::{GameClient, GameClientLocation},
-        phase::PhaseType, player::{PlayerIndex, PlayerInitializeParameters, PlayerReference},
-        spectator::{spectator_pointer::{SpectatorIndex, SpectatorPointer},
+        phase::PhaseType, settings::SpectatorSettings, player::{PlayerIndex, PlayerInitializeParameters, PlayerReference},
+        role_list_generation::probabilities::RoleListProbabilities, spectator::{spectator_pointer::{SpectatorIndex, SpectatorPointer},
         SpectatorInitializeParameters}, Game, RejectStartReason
     }, 
     log, packet::{ToClientPacket, ToServerPacket}, 
@@ -14,7 +13,7 @@ use crate::{
     websocket_connections::connection::ClientSender
 };
 
-use super::{lobby_client::{LobbyClient, LobbyClientType, Ready}, Lobby};
+use super::{lobby_client::{LobbyClient, LobbyClientType, Ready}, EstimatingProbabilities, Lobby};
 
 pub enum LobbyClientMessageResult {
     StartGame(Box<Game>),
@@ -30,21 +29,15 @@ impl Lobby {
                 if text.is_empty() {break 'packet_match}
                 
                 let name = if let Some(
-                    LobbyClient { client_type: LobbyClientType::Player { name }, .. }
+                    LobbyClient { client_type: LobbyClientType::Player { name }, muted: false, .. }
                 ) = self.clients.get(&room_client_id) {
                     name.clone()
                 } else {
                     break 'packet_match
                 };
 
-                self.send_to_all(ToClientPacket::AddChatMessages { chat_messages: vec_map![(
-                    self.chat_message_index,
-                    ChatMessage::new_non_private(
-                        ChatMessageVariant::LobbyMessage { sender: name, text }, 
-                        crate::game::chat::ChatGroup::All
-                    )
-                )]});
-                self.chat_message_index = self.chat_message_index.saturating_add(1);
+                let (index, chat_message) = self.chat.add_message(name, text);
+                self.send_to_all(ToClientPacket::AddChatMessages { chat_messages: vec_map![(index, chat_message)] });
             }
             ToServerPacket::SetSpectator { spectator } => {
                 let player_names = self.clients.values().filter_map(|p| {
@@ -55,7 +48,7 @@ impl Lobby {
                     }
                 }).collect::<Vec<_>>();
 
-                let new_name = name_validation::sanitize_name("".to_string(), &player_names);
+                let new_name = name_validation::sanitize_name("".to_string(), &player_names, &mut self.rng);
 
                 if let Some(player) = self.clients.get_mut(&room_client_id){
                     match &player.client_type {
@@ -111,6 +104,25 @@ impl Lobby {
                 
                 self.send_to_all(ToClientPacket::RoomName { name })
             },
+            ToServerPacket::SetRoomUnlisted { unlisted } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.is_host() {break 'packet_match}
+
+                self.access.unlisted = unlisted;
+                self.send_to_all(ToClientPacket::RoomAccess { unlisted, password_protected: self.access.password_protected() });
+            }
+            ToServerPacket::SetRoomPassword { password } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.is_host() {break 'packet_match}
+
+                self.access.set_password(password);
+                self.send_to_all(ToClientPacket::RoomAccess { unlisted: self.access.unlisted, password_protected: self.access.password_protected() });
+            }
+            ToServerPacket::CreateInviteCode => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.is_host() {break 'packet_match}
+
+                if let Some(code) = self.access.create_invite_code() {
+                    send.send(ToClientPacket::InviteCode { code });
+                }
+            }
             ToServerPacket::StartGame => {
                 if let Some(player) = self.clients.get(&room_client_id) && !player.is_host() {break 'packet_match}
                 
@@ -128,12 +140,14 @@ impl Lobby {
                             GameClient {
                                 client_location: GameClientLocation::Spectator(SpectatorPointer::new(next_spectator_index)),
                                 host: lobby_client.is_host(),
+                                muted: lobby_client.muted,
                                 last_message_times: VecDeque::new(),
                             }
                         } else {
                             GameClient {
                                 client_location: GameClientLocation::Player(unsafe { PlayerReference::new_unchecked(next_player_index) }),
                                 host: lobby_client.is_host(),
+                                muted: lobby_client.muted,
                                 last_message_times: VecDeque::new(),
                             }
                         }
@@ -168,7 +182,7 @@ impl Lobby {
                     }
                 }
 
-                let game = match Game::new(self.name.clone(), self.settings.clone(), game_clients, game_player_params, game_spectator_params){
+                let game = match Game::new(self.name.clone(), self.settings.clone(), self.chat.clone(), self.access.clone(), game_clients, game_player_params, game_spectator_params){
                     Ok(game) => game,
                     Err(err) => {
                         send.send(ToClientPacket::RejectStart { reason: err });
@@ -201,6 +215,7 @@ impl Lobby {
             },
             ToServerPacket::SetPhaseTimes { phase_time_settings } => {
                 if let Some(player) = self.clients.get(&room_client_id) && !player.is_host() {break 'packet_match}
+                if !phase_time_settings.percentages_are_valid() {break 'packet_match}
 
                 self.settings.phase_times = phase_time_settings.clone();
 
@@ -247,6 +262,46 @@ impl Lobby {
                 self.settings.modifiers = modifier_settings.clone();
                 self.send_to_all(ToClientPacket::ModifierSettings { modifier_settings });
             }
+            ToServerPacket::SetSpectatorSettings { mut spectator_settings } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.is_host() {break 'packet_match}
+
+                spectator_settings.feed_delay = spectator_settings.feed_delay.min(SpectatorSettings::MAX_FEED_DELAY);
+                self.settings.spectators = spectator_settings.clone();
+                self.send_to_all(ToClientPacket::SpectatorSettings { spectator_settings });
+            }
+            ToServerPacket::SetTrialSettings { trial_settings } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.is_host() {break 'packet_match}
+
+                self.settings.trials = trial_settings.clone();
+                self.send_to_all(ToClientPacket::TrialSettings { trial_settings });
+            }
+            ToServerPacket::SetInactivitySettings { mut inactivity_settings } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.is_host() {break 'packet_match}
+
+                inactivity_settings.action_after = inactivity_settings.action_after.max(inactivity_settings.warning_after);
+                self.settings.inactivity = inactivity_settings.clone();
+                self.send_to_all(ToClientPacket::InactivitySettings { inactivity_settings });
+            }
+            ToServerPacket::SetPhaseSchedule { phase_schedule } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.is_host() {break 'packet_match}
+                if !phase_schedule.is_valid() {break 'packet_match}
+
+                self.settings.phase_schedule = phase_schedule.clone();
+                self.send_to_all(ToClientPacket::PhaseSchedule { phase_schedule });
+            }
+            ToServerPacket::RoleListProbabilitiesRequest => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.is_host() {break 'packet_match}
+                let Some(estimating) = EstimatingProbabilities::start(&self.estimating_probabilities) else {break 'packet_match};
+
+                // Sampling takes too long to do during a tick, so only the host who asked waits for it
+                let settings = self.settings.clone();
+                let send = send.clone();
+                tokio::task::spawn_blocking(move || {
+                    let probabilities = RoleListProbabilities::estimate(&settings, RoleListProbabilities::SAMPLES);
+                    send.send(ToClientPacket::RoleListProbabilities { probabilities });
+                    drop(estimating);
+                });
+            }
             ToServerPacket::Leave => {
                 if let RemoveRoomClientResult::RoomShouldClose = self.remove_client(room_client_id) {
                     return LobbyClientMessageResult::Close;
@@ -257,6 +312,27 @@ impl Lobby {
 
                 self.set_player_name(id, name);
             }
+            ToServerPacket::HostSetClientMuted { id, muted } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.is_host() { break 'packet_match }
+
+                if let Some(client) = self.clients.get_mut(&id) {
+                    client.muted = muted;
+                }
+                self.send_players();
+            }
+            ToServerPacket::HostDeleteLobbyMessage { index } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.is_host() { break 'packet_match }
+
+                if self.chat.delete_message(index) {
+                    self.send_to_all(ToClientPacket::LobbyMessageDeleted { index });
+                }
+            }
+            ToServerPacket::SetLobbyWordFilter { words } => {
+                if let Some(player) = self.clients.get(&room_client_id) && !player.is_host() { break 'packet_match }
+
+                self.chat.set_word_filter(words);
+                self.send_to_all(ToClientPacket::LobbyWordFilter { words: self.chat.word_filter().clone() });
+            }
             ToServerPacket::SetPlayerHost { player_id } => {
                 if let Some(player) = self.clients.get(&room_client_id) && !player.is_host() { break 'packet_match }
 
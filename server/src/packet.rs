@@ -21,7 +21,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use vec1::Vec1;
 
-use crate::{client_connection::ClientConnection, game::{GameOverReason, RejectStartReason, abilities_component::ability_id::AbilityID, chat::{ChatGroup, ChatMessage, ChatMessageIndex}, components::{fast_forward::FastForwardSetting, graves::{grave::Grave, grave_reference::GraveReference}, insider_group::InsiderGroupID, tags::Tag}, controllers::{Controller, ControllerID, ControllerInput}, game_client::GameClientLocation, modifiers::ModifierSettings, phase::{PhaseState, PhaseType}, player::{PlayerIndex, PlayerReference}, role::{ClientRoleStateEnum, Role}, role_list::{RoleList, RoleOutline}, settings::PhaseTimeSettings}, lobby::lobby_client::LobbyClient, room::RoomClientID, vec_map::VecMap, vec_set::VecSet, websocket_listener::RoomCode};
+use crate::{client_connection::ClientConnection, game::{GameOverReason, RejectStartReason, abilities_component::ability_id::AbilityID, chat::{ChatGroup, ChatMessage, ChatMessageIndex}, components::{fast_forward::FastForwardSetting, graves::{grave::Grave, grave_reference::GraveReference}, insider_group::InsiderGroupID, tags::Tag}, controllers::{Controller, ControllerID, ControllerInput}, game_client::GameClientLocation, modifiers::{ModifierID, ModifierSettings}, phase::{PhaseState, PhaseType}, phase_schedule::PhaseSchedule, player::{PlayerIndex, PlayerReference}, spectator::spectator_pointer::SpectatorIndex, role::{ClientRoleStateEnum, Role}, role_list::{RoleList, RoleOutline}, role_list_generation::probabilities::RoleListProbabilities, settings::{InactivitySettings, PhaseTimeSettings, SpectatorSettings, TrialSettings}}, lobby::lobby_client::LobbyClient, room::RoomClientID, vec_map::VecMap, vec_set::VecSet, websocket_listener::RoomCode};
 
 
 #[derive(Serialize, Debug, Clone)]
@@ -29,7 +29,49 @@ use crate::{client_connection::ClientConnection, game::{GameOverReason, RejectSt
 pub struct RoomPreviewData {
     pub name: String,
     pub in_game: bool,
-    pub players: Vec<(RoomClientID, String)>
+    pub players: Vec<(RoomClientID, String)>,
+    pub spectators: usize,
+    /// None if the host is a spectator
+    pub host_name: Option<String>,
+    /// Only set once the game has started
+    pub phase: Option<(PhaseType, u8)>,
+    pub role_list_size: usize,
+    pub modifiers: Vec<ModifierID>,
+    pub spectators_allowed: bool,
+    pub password_protected: bool,
+}
+
+impl RoomPreviewData {
+    /// Players and spectators
+    pub fn size(&self) -> usize {
+        self.players.len().saturating_add(self.spectators)
+    }
+    /// Whether a new client could join this room without it growing past `max_size`.
+    /// Clients join lobbies as players and games as spectators.
+    pub fn has_space(&self, max_size: Option<usize>) -> bool {
+        let can_join = if self.in_game {
+            self.spectators_allowed && self.spectators < SpectatorIndex::MAX as usize
+        } else {
+            self.players.len() < PlayerIndex::MAX as usize
+        };
+        can_join && max_size.is_none_or(|max_size| self.size() < max_size)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RoomListFilter {
+    pub not_in_game: bool,
+    pub has_space: bool,
+    pub max_players: Option<usize>,
+}
+
+impl RoomListFilter {
+    pub fn matches(&self, room: &RoomPreviewData) -> bool {
+        (!self.not_in_game || !room.in_game) &&
+        (!self.has_space || room.has_space(self.max_players)) &&
+        self.max_players.is_none_or(|max_players| room.size() <= max_players)
+    }
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -57,7 +99,9 @@ pub enum ToClientPacket{
     #[serde(rename = "lobbyList", rename_all = "camelCase")]
     RoomList{
         #[serde(rename = "lobbies")]
-        rooms: HashMap<RoomCode, RoomPreviewData>
+        rooms: HashMap<RoomCode, RoomPreviewData>,
+        page: usize,
+        page_count: usize,
     },
     #[serde(rename_all = "camelCase")]
     AcceptJoin{room_code: RoomCode, in_game: bool, player_id: RoomClientID, spectator: bool},
@@ -74,6 +118,11 @@ pub enum ToClientPacket{
     PlayersReady{ready: Vec<RoomClientID>},
     #[serde(rename_all = "camelCase")]
     PlayersLostConnection{lost_connection: Vec<RoomClientID>},
+    LobbyMessageDeleted{index: ChatMessageIndex},
+    LobbyWordFilter{words: Vec<String>},
+    #[serde(rename_all = "camelCase")]
+    RoomAccess{unlisted: bool, password_protected: bool},
+    InviteCode{code: String},
     StartGame,
     #[serde(rename_all = "camelCase")]
     RejectStart{reason: RejectStartReason},
@@ -93,6 +142,15 @@ pub enum ToClientPacket{
     EnabledRoles{roles: Vec<Role>},
     #[serde(rename_all = "camelCase")]
     ModifierSettings{modifier_settings: ModifierSettings},
+    #[serde(rename_all = "camelCase")]
+    SpectatorSettings{spectator_settings: SpectatorSettings},
+    #[serde(rename_all = "camelCase")]
+    TrialSettings{trial_settings: TrialSettings},
+    #[serde(rename_all = "camelCase")]
+    InactivitySettings{inactivity_settings: InactivitySettings},
+    #[serde(rename_all = "camelCase")]
+    PhaseSchedule{phase_schedule: PhaseSchedule},
+    RoleListProbabilities{probabilities: RoleListProbabilities},
 
     // Host
     HostData { clients: VecMap<RoomClientID, HostDataPacketGameClient> },
@@ -109,10 +167,12 @@ pub enum ToClientPacket{
     Phase{phase: PhaseState, day_number: u8},
     #[serde(rename_all = "camelCase")]
     PhaseTimeLeft{seconds_left: Option<u16>},
+    #[serde(rename_all = "camelCase")]
+    GamePaused{paused: bool, resume_in: Option<u16>},
 
     PlayerAlive{alive: Vec<bool>},
     #[serde(rename_all = "camelCase")]
-    PlayerVotes{votes_for_player: VecMap<PlayerReference, u8>},
+    PlayerVotes{votes_for_player: VecMap<PlayerReference, u8>, revote_candidates: Option<VecSet<PlayerReference>>},
 
     #[serde(rename_all = "camelCase")]
     YourSendChatGroups{send_chat_groups: VecSet<ChatGroup>},
@@ -161,6 +221,8 @@ pub enum RejectJoinReason {
     RoomFull,
     RoomDoesntExist,
     ServerBusy,
+    WrongPassword,
+    Banned,
 
     PlayerTaken,
     PlayerDoesntExist,
@@ -171,16 +233,16 @@ pub enum RejectJoinReason {
 pub enum ToServerPacket{
     Ping,
     // Pre Lobby
-    #[serde(rename = "lobbyListRequest")]
-    RoomListRequest,
+    #[serde(rename = "lobbyListRequest", rename_all = "camelCase")]
+    RoomListRequest{#[serde(default)] filter: RoomListFilter, #[serde(default)] page: usize},
     #[serde(rename_all = "camelCase")]
     ReJoin{room_code: RoomCode, player_id: RoomClientID},
     #[serde(rename_all = "camelCase")]
-    Join{room_code: RoomCode},
+    Join{room_code: RoomCode, password: Option<String>, invite_code: Option<String>},
     Host,
     Leave,
     #[serde(rename_all = "camelCase")]
-    Kick{player_id: RoomClientID},
+    Kick{player_id: RoomClientID, #[serde(default)] ban: bool},
     #[serde(rename_all = "camelCase")]
     SetPlayerHost{player_id: RoomClientID},
     RelinquishHost,
@@ -192,6 +254,10 @@ pub enum ToServerPacket{
     ReadyUp{ready: bool},
     #[serde(rename = "setLobbyName")]
     SetRoomName{name: String},
+    SetLobbyWordFilter{words: Vec<String>},
+    SetRoomUnlisted{unlisted: bool},
+    SetRoomPassword{password: Option<String>},
+    CreateInviteCode,
     StartGame,
 
     // Settings
@@ -211,13 +277,33 @@ pub enum ToServerPacket{
     SetEnabledRoles{roles: Vec<Role>},
     #[serde(rename_all = "camelCase")]
     SetModifierSettings{modifier_settings: ModifierSettings},
+    #[serde(rename_all = "camelCase")]
+    SetSpectatorSettings{spectator_settings: SpectatorSettings},
+    #[serde(rename_all = "camelCase")]
+    SetTrialSettings{trial_settings: TrialSettings},
+    #[serde(rename_all = "camelCase")]
+    SetInactivitySettings{inactivity_settings: InactivitySettings},
+    #[serde(rename_all = "camelCase")]
+    SetPhaseSchedule{phase_schedule: PhaseSchedule},
+    RoleListProbabilitiesRequest,
 
     // Host
     HostDataRequest,
     HostForceBackToLobby,
     HostForceEndGame,
     HostForceSkipPhase,
+    HostPauseGame,
+    HostResumeGame { countdown: Option<u16> },
+    HostAdjustPhaseTime { seconds: i32 },
+    #[serde(rename_all = "camelCase")]
+    HostSetPhaseTimes { phase_time_settings: PhaseTimeSettings },
+    HostModkill { id: RoomClientID },
+    #[serde(rename_all = "camelCase")]
+    HostReplacePlayer { id: RoomClientID, replacement_id: RoomClientID },
+    HostForceReveal { id: RoomClientID },
     HostForceSetPlayerName { id: RoomClientID, name: String },
+    HostSetClientMuted { id: RoomClientID, muted: bool },
+    HostDeleteLobbyMessage { index: ChatMessageIndex },
 
     // Game
     SaveNotes{notes: Vec<String>},
@@ -225,6 +311,7 @@ pub enum ToServerPacket{
     SaveCrossedOutOutlines{crossed_out_outlines: Vec<u8>},
     #[serde(rename_all = "camelCase")]
     SaveCallingCard{calling_card: Option<String>},
+    SetWaitingToReplace{waiting: bool},
 
     // ControllerInput
     #[serde(rename_all = "camelCase")]
@@ -1,10 +1,13 @@
 
 pub mod on_client_message;
 pub mod name_validation;
+pub mod room_access;
 
 use std::time::Duration;
 
 use super::lobby::Lobby;
+use rand::rngs::SmallRng;
+use room_access::RoomAccess;
 
 use crate::{
     game::Game, packet::{RoomPreviewData, RejectJoinReason, ToClientPacket}, websocket_connections::connection::ClientSender
@@ -53,7 +56,19 @@ pub struct RoomTickResult {
 }
 
 impl Room {
-    pub fn new() -> Room {
-        Room::Lobby(Lobby::new())
+    pub fn new(rng: SmallRng) -> Room {
+        Room::Lobby(Lobby::with_rng(rng))
+    }
+    pub fn access(&self) -> &RoomAccess {
+        match self {
+            Self::Lobby(lobby) => &lobby.access,
+            Self::Game(game) => game.access(),
+        }
+    }
+    pub fn access_mut(&mut self) -> &mut RoomAccess {
+        match self {
+            Self::Lobby(lobby) => &mut lobby.access,
+            Self::Game(game) => game.access_mut(),
+        }
     }
 }
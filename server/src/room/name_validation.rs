@@ -1,6 +1,6 @@
 use crate::strings::TidyableString;
 use lazy_static::lazy_static;
-use rand::seq::IndexedRandom;
+use rand::{seq::IndexedRandom, Rng};
 
 lazy_static!(
     static ref RANDOM_NAMES: Vec<String> = {
@@ -27,7 +27,7 @@ pub const DEFAULT_SERVER_NAME: &str = "Mafia Room";
 /// Sanitizes a player name.
 /// If the desired name is invalid or taken, this generates a random acceptable name.
 /// Otherwise, this trims and returns the input name.
-pub fn sanitize_name(mut desired_name: String, other_names: &[String]) -> String {
+pub fn sanitize_name<R: Rng + ?Sized>(mut desired_name: String, other_names: &[String], rng: &mut R) -> String {
     desired_name = desired_name
         .remove_newline()
         .trim_whitespace()
@@ -39,7 +39,7 @@ pub fn sanitize_name(mut desired_name: String, other_names: &[String]) -> String
     if !desired_name.is_empty() && !name_already_taken {
         desired_name
     } else {
-        generate_random_name(&other_names.iter().map(|s| s.as_str()).collect::<Vec<&str>>())
+        generate_random_name(&other_names.iter().map(|s| s.as_str()).collect::<Vec<&str>>(), rng)
     }
 }
 
@@ -51,7 +51,7 @@ pub fn sanitize_server_name(desired_name: String) -> String {
         .truncate_lines(1)
 }
 
-pub fn generate_random_name(taken_names: &[&str]) -> String{
+pub fn generate_random_name<R: Rng + ?Sized>(taken_names: &[&str], rng: &mut R) -> String{
     let taken_names_str = taken_names.iter().map(
         |existing_name|
         existing_name.to_string()
@@ -64,7 +64,7 @@ pub fn generate_random_name(taken_names: &[&str]) -> String{
         !taken_names_str.contains(new_random_name)
     ).collect::<Vec<&String>>();
 
-    if let Some(random_name) = available_random_names.choose(&mut rand::rng()) {
+    if let Some(random_name) = available_random_names.choose(rng) {
         (*random_name).clone()
     } else {
         let mut i: u16 = 0;
@@ -14,7 +14,8 @@ pub enum RoomClientMessageResult {
 } 
 
 impl Room {
-    pub fn on_client_message(&mut self, send: &ClientSender, room_client_id: RoomClientID, incoming_packet: ToServerPacket) -> RoomClientMessageResult {
+    /// `now` is the time on the listener's clock, for the rate limiter
+    pub fn on_client_message(&mut self, send: &ClientSender, room_client_id: RoomClientID, incoming_packet: ToServerPacket, now: Instant) -> RoomClientMessageResult {
         //RATE LIMITER
         match incoming_packet {
             ToServerPacket::SetName { .. } |
@@ -28,7 +29,6 @@ impl Room {
                     return RoomClientMessageResult::None;
                 };
 
-                let now = Instant::now();
                 while let Some(time) = last_message_times.front() {
                     if now.duration_since(*time) > MESSAGE_PER_SECOND_LIMIT_TIME {
                         last_message_times.pop_front();
@@ -0,0 +1,108 @@
+use std::net::IpAddr;
+
+use rand::{distr::Alphanumeric, Rng};
+
+use crate::{packet::RejectJoinReason, room::RoomClientID, strings::TidyableString, vec_set::VecSet};
+
+/// Controls who can see the room in the room list and who can join it.
+#[derive(Clone, Debug, Default)]
+pub struct RoomAccess {
+    /// Unlisted rooms don't appear in the room list, but can still be joined with the room code
+    pub unlisted: bool,
+    password: Option<String>,
+    invite_codes: VecSet<String>,
+    /// Bans last for the lifetime of the room
+    banned_addresses: VecSet<IpAddr>,
+    banned_client_ids: VecSet<RoomClientID>,
+}
+
+impl RoomAccess {
+    const MAX_PASSWORD_LENGTH: usize = 30;
+    const MAX_INVITE_CODES: usize = 50;
+    const INVITE_CODE_LENGTH: usize = 8;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn password_protected(&self) -> bool {
+        self.password.is_some()
+    }
+
+    /// An empty password removes the password
+    pub fn set_password(&mut self, password: Option<String>) {
+        self.password = password
+            .map(Self::normalize_password)
+            .filter(|password| !password.is_empty());
+    }
+
+    /// Passwords are stored and checked like this, so typing the password the host set always works
+    fn normalize_password(password: String) -> String {
+        password.remove_newline().truncate(Self::MAX_PASSWORD_LENGTH)
+    }
+
+    /// Creates a single use code that lets one client join without the password.
+    /// Returns None if too many codes are unused.
+    pub fn create_invite_code(&mut self) -> Option<String> {
+        if self.invite_codes.count() >= Self::MAX_INVITE_CODES {
+            return None;
+        }
+
+        loop {
+            // Not from the room's rng, because invite codes are secrets and don't affect the game
+            let code: String = rand::rng()
+                .sample_iter(&Alphanumeric)
+                .take(Self::INVITE_CODE_LENGTH)
+                .map(char::from)
+                .collect();
+
+            if self.invite_codes.insert(code.clone()).is_none() {
+                return Some(code);
+            }
+        }
+    }
+
+    /// Stops this address from joining, and stops anyone from rejoining as this client
+    pub fn ban(&mut self, address: Option<IpAddr>, room_client_id: RoomClientID) {
+        if let Some(address) = address {
+            self.banned_addresses.insert(address);
+        }
+        self.banned_client_ids.insert(room_client_id);
+    }
+
+    pub fn is_banned(&self, address: IpAddr) -> bool {
+        self.banned_addresses.contains(&address)
+    }
+
+    pub fn is_client_id_banned(&self, room_client_id: RoomClientID) -> bool {
+        self.banned_client_ids.contains(&room_client_id)
+    }
+
+    /// Banned client ids are never given out again, so a new client can't inherit a ban
+    pub fn banned_client_ids(&self) -> impl Iterator<Item = RoomClientID> {
+        self.banned_client_ids.iter().copied()
+    }
+
+    /// Checks whether a client with these credentials may join.
+    /// Returns the invite code which should be used up if the client successfully joins.
+    pub fn check_join(&self, address: IpAddr, password: Option<&str>, invite_code: Option<&str>) -> Result<Option<String>, RejectJoinReason> {
+        if self.is_banned(address) {
+            return Err(RejectJoinReason::Banned);
+        }
+
+        let Some(room_password) = &self.password else { return Ok(None) };
+
+        if password.is_some_and(|password| Self::normalize_password(password.to_owned()) == *room_password) {
+            return Ok(None);
+        }
+
+        match invite_code {
+            Some(code) if self.invite_codes.contains(&code.to_string()) => Ok(Some(code.to_string())),
+            _ => Err(RejectJoinReason::WrongPassword)
+        }
+    }
+
+    pub fn use_invite_code(&mut self, code: &String) {
+        self.invite_codes.remove(code);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{log, websocket_connections::{connection::Connection, ForceLock}, websocket_listener::WebsocketListener};
+use crate::{clock::ClockSpeed, log, websocket_connections::{connection::Connection, ForceLock}, websocket_listener::WebsocketListener};
 use tokio_tungstenite::tungstenite::Message;
 use std::{future::Future, net::SocketAddr, pin::pin, sync::{Arc, Mutex}};
 
@@ -27,7 +27,9 @@ pub async fn create_ws_server(server_address: &str) {
         }))
     }
 
-    let event_listener: Arc<Mutex<_>> = Arc::new(Mutex::new(WebsocketListener::new()));
+    let event_listener: Arc<Mutex<_>> = Arc::new(Mutex::new(
+        WebsocketListener::new().with_speed(ClockSpeed::from_env().unwrap_or_default())
+    ));
     WebsocketListener::start_tick(event_listener.clone());
 
     log!(important "Server"; "Started listening on {server_address}");
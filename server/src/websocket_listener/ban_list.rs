@@ -0,0 +1,73 @@
+use std::{net::IpAddr, path::PathBuf, time::SystemTime};
+
+use crate::{log, vec_set::VecSet};
+
+/// IP addresses that can't join or host any room on this server.
+///
+/// The list is read from the file at `BAN_LIST_PATH`, one address per line.
+/// Lines starting with `#` are comments. The file is reloaded whenever it changes,
+/// so an operator can ban or unban someone without restarting the server.
+/// Anyone already connected from a banned address is disconnected when the file is reloaded.
+pub(super) struct ServerBanList {
+    path: Option<PathBuf>,
+    last_modified: Option<SystemTime>,
+    banned_addresses: VecSet<IpAddr>,
+}
+
+impl ServerBanList {
+    const PATH_ENVIRONMENT_VARIABLE: &str = "BAN_LIST_PATH";
+
+    pub(super) fn from_env() -> Self {
+        Self::from_path(std::env::var(Self::PATH_ENVIRONMENT_VARIABLE).ok().map(PathBuf::from))
+    }
+
+    pub(super) fn from_path(path: Option<PathBuf>) -> Self {
+        let mut ban_list = Self {
+            path,
+            last_modified: None,
+            banned_addresses: VecSet::new(),
+        };
+        ban_list.reload_if_changed();
+        ban_list
+    }
+
+    pub(super) fn is_banned(&self, address: IpAddr) -> bool {
+        self.banned_addresses.contains(&address)
+    }
+
+    /// Returns true if anyone could have been banned by the reload
+    pub(super) fn reload_if_changed(&mut self) -> bool {
+        let Some(path) = &self.path else { return false };
+
+        let Ok(modified) = std::fs::metadata(path).and_then(|metadata| metadata.modified()) else {
+            if !self.banned_addresses.is_empty() {
+                log!(error "Ban List"; "Couldn't read {}, unbanning everyone", path.display());
+                self.banned_addresses.clear();
+            }
+            self.last_modified = None;
+            return false
+        };
+        if self.last_modified == Some(modified) { return false }
+
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            log!(error "Ban List"; "Couldn't read {}", path.display());
+            return false
+        };
+
+        self.last_modified = Some(modified);
+        self.banned_addresses = contents.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| match line.parse() {
+                Ok(address) => Some(address),
+                Err(_) => {
+                    log!(error "Ban List"; "Invalid address in ban list: {}", line);
+                    None
+                }
+            })
+            .collect();
+
+        log!(important "Ban List"; "Loaded {} banned addresses", self.banned_addresses.count());
+        !self.banned_addresses.is_empty()
+    }
+}
@@ -1,4 +1,4 @@
-use std::{net::SocketAddr, ops::Mul, time::Duration};
+use std::{net::SocketAddr, ops::Mul, time::{Duration, Instant}};
 
 use crate::{room::{RoomClientID, Room}, packet::ToClientPacket, websocket_connections::connection::{ClientSender, Connection}};
 
@@ -63,11 +63,11 @@ impl ClientReference{
         self.deref(listener).in_room(room_code)
     }
 
-    pub(super) fn tick(&self, listener: &mut WebsocketListener){
-        self.deref_mut(listener).tick();
+    pub(super) fn tick(&self, listener: &mut WebsocketListener, now: Instant){
+        self.deref_mut(listener).tick(now);
     }
-    pub(super) fn ping_timed_out(&self, listener: &WebsocketListener)->bool{
-        self.deref(listener).ping_timed_out()
+    pub(super) fn ping_timed_out(&self, listener: &WebsocketListener, now: Instant)->bool{
+        self.deref(listener).ping_timed_out(now)
     }
 }
 
@@ -75,26 +75,26 @@ impl ClientReference{
 pub(super) struct Client {
     connection: Connection,
     location: ClientLocation,
-    last_ping: tokio::time::Instant,
+    last_ping: Instant,
 }
 impl Client{
     const PONG_INTERVAL: Duration = Duration::from_secs(5);
 
-    pub(super) fn new(connection: Connection) -> Self {
+    pub(super) fn new(connection: Connection, now: Instant) -> Self {
         Self {
             connection,
             location: ClientLocation::OutsideRoom,
-            last_ping: tokio::time::Instant::now(),
+            last_ping: now,
         }
     }
-    pub(super) fn on_ping(&mut self) {
-        self.last_ping = tokio::time::Instant::now();
+    pub(super) fn on_ping(&mut self, now: Instant) {
+        self.last_ping = now;
     }
-    pub(super) fn ping_timed_out(&self) -> bool {
-        self.last_ping.elapsed() > Self::PONG_INTERVAL.mul(2)
+    pub(super) fn ping_timed_out(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.last_ping) > Self::PONG_INTERVAL.mul(2)
     }
-    pub(super) fn tick(&mut self) {
-        if Self::PONG_INTERVAL < tokio::time::Instant::now().saturating_duration_since(self.last_ping) {
+    pub(super) fn tick(&mut self, now: Instant) {
+        if Self::PONG_INTERVAL < now.saturating_duration_since(self.last_ping) {
             self.connection.send(ToClientPacket::Pong);
         }
     }
@@ -1,4 +1,3 @@
-use std::time::Duration;
 use tokio_tungstenite::tungstenite::Message;
 
 use crate::{room::RoomState, log, packet::ToServerPacket, websocket_connections::connection::Connection};
@@ -36,20 +35,37 @@ impl WebsocketListener{
             }
         }
     }
-    pub(super) fn tick(&mut self, delta_time: Duration){
+    /// Passes however much time the listener's clock says has passed since the last tick.
+    /// Rooms get that time sped up by the listener's [`ClockSpeed`](crate::clock::ClockSpeed), clients get it as it is.
+    /// Called every second by [`WebsocketListener::start_tick`], or whenever a test wants.
+    pub fn tick(&mut self){
+        let now = self.clock.now();
+        let delta_time = now.saturating_duration_since(self.last_tick);
+        self.last_tick = now;
+
         let mut closed_rooms = Vec::new();
         let mut closed_clients = Vec::new();
 
+        if self.ban_list.reload_if_changed() {
+            for client in ClientReference::all_clients(self) {
+                if self.is_banned_from_server(&client) {
+                    log!(important "Listener"; "Closed connection {} because it was banned", client.address(self));
+                    self.delete_client(&client);
+                }
+            }
+        }
+
+        let room_delta_time = self.speed.scale(delta_time);
         for (room_code, room) in self.rooms_mut().iter_mut() {
-            let tick_data = room.tick(delta_time);
+            let tick_data = room.tick(room_delta_time);
             if tick_data.close_room {
                 closed_rooms.push(*room_code);
             }
         }
 
         for client in ClientReference::all_clients(self){
-            client.tick(self);
-            if client.ping_timed_out(self) {
+            client.tick(self, now);
+            if client.ping_timed_out(self, now) {
                 closed_clients.push(client);
             }
         }
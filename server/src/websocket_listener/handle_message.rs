@@ -9,23 +9,43 @@ impl WebsocketListener{
 
         match packet {
             ToServerPacket::Ping => {
-                client.deref_mut(self).on_ping();
+                let now = self.clock.now();
+                client.deref_mut(self).on_ping(now);
             },
-            ToServerPacket::RoomListRequest => {
+            ToServerPacket::RoomListRequest { filter, page } => {
+                let mut rooms = self.rooms()
+                    .iter()
+                    .filter(|(_, room)| !room.access().unlisted)
+                    .map(|(room_code, room)| (*room_code, room.get_preview_data()))
+                    .filter(|(_, preview)| filter.matches(preview))
+                    .collect::<Vec<(RoomCode, RoomPreviewData)>>();
+                rooms.sort_by_key(|(room_code, _)| *room_code);
+
+                let page_count = rooms.len().div_ceil(Self::ROOM_LIST_PAGE_SIZE).max(1);
+                let page = page.min(page_count.saturating_sub(1));
+
                 client.send(
                     self,
-                    ToClientPacket::RoomList{rooms: self.rooms()
-                        .iter()
-                        .map(|(room_code, room)| (*room_code, room.get_preview_data()))
-                        .collect::<HashMap<RoomCode, RoomPreviewData>>()
+                    ToClientPacket::RoomList{
+                        rooms: rooms.into_iter()
+                            .skip(page.saturating_mul(Self::ROOM_LIST_PAGE_SIZE))
+                            .take(Self::ROOM_LIST_PAGE_SIZE)
+                            .collect::<HashMap<RoomCode, RoomPreviewData>>(),
+                        page,
+                        page_count,
                     }
                 );
             },
+            ToServerPacket::ReJoin { .. } |
+            ToServerPacket::Join { .. } |
+            ToServerPacket::Host if self.is_banned_from_server(&client) => {
+                client.send(self, ToClientPacket::RejectJoin { reason: RejectJoinReason::Banned });
+            }
             ToServerPacket::ReJoin {room_code, player_id } => {
                 self.set_client_in_room_reconnect(client, room_code, player_id);
             }
-            ToServerPacket::Join{ room_code } => {
-                self.set_client_in_room(&client, room_code);
+            ToServerPacket::Join{ room_code, password, invite_code } => {
+                self.set_client_in_room(&client, room_code, password, invite_code);
             },
             ToServerPacket::Host => {
                 let Some(room_code) = self.create_room() else {
@@ -33,14 +53,14 @@ impl WebsocketListener{
                     return;
                 };
                 
-                self.set_client_in_room(&client, room_code);
+                self.set_client_in_room(&client, room_code, None, None);
 
                 log!(important "Room"; "Created {room_code}");
             },
             ToServerPacket::Leave => {
                 self.set_client_outside_room(&client, false);
             },
-            ToServerPacket::Kick { player_id: kicked_player_id } => {
+            ToServerPacket::Kick { player_id: kicked_player_id, ban } => {
                 let Ok((room,room_code,host_id)) = client.get_room_mut(self) else {return};
 
                 if !room.is_host(host_id) {return}
@@ -52,9 +72,17 @@ impl WebsocketListener{
                         *c.location(self) == ClientLocation::InRoom { room_code, room_client_id: kicked_player_id }
                     );
 
+                if ban {
+                    let address = kicked_player_client_ref.as_ref().map(|c| c.address(self).ip());
+                    if let Ok((room,_,_)) = client.get_room_mut(self) {
+                        room.access_mut().ban(address, kicked_player_id);
+                    }
+                }
+
                 if let Some(kicked_player_client_ref) = kicked_player_client_ref {
 
-                    kicked_player_client_ref.send(self, ToClientPacket::RejectJoin { reason: RejectJoinReason::ServerBusy });
+                    let reason = if ban { RejectJoinReason::Banned } else { RejectJoinReason::ServerBusy };
+                    kicked_player_client_ref.send(self, ToClientPacket::RejectJoin { reason });
                     self.set_client_outside_room(&kicked_player_client_ref, false);
                     
                 } else { // The client wasn't in the Websocket Listener
@@ -72,16 +100,17 @@ impl WebsocketListener{
             },
             _ => {
                 let sender = &client.sender(self);
+                let now = self.clock.now();
                 let Ok((room, room_code, id)) = client.get_room_mut(self) else {return};
 
-                match room.on_client_message(sender, id, packet) {
+                match room.on_client_message(sender, id, packet, now) {
                     RoomClientMessageResult::LobbyAction(LobbyClientMessageResult::StartGame(game)) => {
                         log!(info "Room"; "Game started with room code {}", room_code);
 
                         *room = Room::Game(*game);
                     },
                     RoomClientMessageResult::GameAction(GameClientMessageResult::BackToLobby(lobby)) => {
-                        *room = Room::Lobby(lobby);
+                        *room = Room::Lobby(*lobby);
                     },
                     RoomClientMessageResult::GameAction(GameClientMessageResult::Close) |
                     RoomClientMessageResult::LobbyAction(LobbyClientMessageResult::Close) => {
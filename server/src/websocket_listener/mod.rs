@@ -1,16 +1,18 @@
 mod event;
 mod client;
 mod handle_message;
+mod ban_list;
 
 pub type RoomCode = usize;
 
 
-use std::{collections::HashMap, net::SocketAddr, sync::{Arc, Mutex}, time::Duration};
+use std::{collections::HashMap, net::SocketAddr, path::PathBuf, sync::{Arc, Mutex}, time::{Duration, Instant}};
 
-use crate::{log, packet::{RejectJoinReason, ToClientPacket}, room::{JoinRoomClientResult, RemoveRoomClientResult, Room, RoomClientID, RoomState}, websocket_connections::connection::Connection};
+use crate::{clock::{Clock, ClockSpeed, SystemClock}, log, packet::{RejectJoinReason, ToClientPacket}, room::{JoinRoomClientResult, RemoveRoomClientResult, Room, RoomClientID, RoomState}, websocket_connections::connection::Connection};
 
+use self::ban_list::ServerBanList;
 use self::client::{Client, ClientLocation, ClientReference, GetRoomError};
-use rand::random;
+use rand::{rngs::SmallRng, Rng, SeedableRng};
 
 
 
@@ -27,14 +29,47 @@ pub struct WebsocketListener {
     ///  Yes                 | Yes              | Hooray!
     clients: HashMap<SocketAddr, Client>,
     rooms: HashMap<RoomCode, Box<Room>>,
+    ban_list: ServerBanList,
+    /// Everything the listener and its rooms do with time is measured on this
+    clock: Box<dyn Clock>,
+    /// Rooms are ticked this much faster than the clock
+    speed: ClockSpeed,
+    last_tick: Instant,
+    /// Room codes, and every room's rng, come from this
+    rng: SmallRng,
 }
 impl WebsocketListener{
+    const ROOM_LIST_PAGE_SIZE: usize = 30;
+
     pub fn new() -> Self {
+        Self::with_clock(Box::new(SystemClock))
+    }
+    pub fn with_clock(clock: Box<dyn Clock>) -> Self {
         Self {
             rooms: HashMap::new(),
             clients: HashMap::new(),
+            ban_list: ServerBanList::from_env(),
+            last_tick: clock.now(),
+            clock,
+            speed: ClockSpeed::REAL_TIME,
+            rng: SmallRng::from_os_rng(),
         }
     }
+    /// Makes time pass faster in rooms, without changing how long clients have to ping or how fast they can send packets
+    pub fn with_speed(mut self, speed: ClockSpeed) -> Self {
+        self.speed = speed;
+        self
+    }
+    /// Makes room codes and random lobby names the same every run. Games are seeded by their own settings.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = SmallRng::seed_from_u64(seed);
+        self
+    }
+    /// Reads the ban list from `path` instead of `BAN_LIST_PATH`
+    pub fn with_ban_list(mut self, path: PathBuf) -> Self {
+        self.ban_list = ServerBanList::from_path(Some(path));
+        self
+    }
     fn clients(&self) -> &HashMap<SocketAddr, Client> {
         &self.clients
     }
@@ -64,7 +99,7 @@ impl WebsocketListener{
             self.delete_client(&client_already_exists);
         }
 
-        self.clients.insert(*connection.address(), Client::new(connection.clone()));
+        self.clients.insert(*connection.address(), Client::new(connection.clone(), self.clock.now()));
     }
     fn delete_client(&mut self, client: &ClientReference) {
         let Some(client) = self.clients.remove(&client.address(self).clone()) else {return};
@@ -85,15 +120,27 @@ impl WebsocketListener{
     }
 
 
-    fn set_client_in_room(&mut self, client: &ClientReference, room_code: RoomCode){
+    fn set_client_in_room(&mut self, client: &ClientReference, room_code: RoomCode, password: Option<String>, invite_code: Option<String>){
 
         let sender = &client.sender(self).clone();
+        let address = client.address(self).ip();
         let Some(room) = self.get_room_mut(&room_code) else {
             client.send(self, ToClientPacket::RejectJoin { reason: RejectJoinReason::RoomDoesntExist });
             return
         };
+        let used_invite_code = match room.access().check_join(address, password.as_deref(), invite_code.as_deref()) {
+            Ok(used_invite_code) => used_invite_code,
+            Err(reason) => {
+                client.send(self, ToClientPacket::RejectJoin { reason });
+                return
+            }
+        };
         match room.join_client(sender) {
             Ok(JoinRoomClientResult { id: room_client_id, in_game, spectator }) => {
+                if let Some(code) = used_invite_code {
+                    room.access_mut().use_invite_code(&code);
+                }
+
                 sender.send(ToClientPacket::AcceptJoin { room_code, in_game, player_id: room_client_id, spectator });
 
                 room.initialize_client(room_client_id, sender);
@@ -108,10 +155,15 @@ impl WebsocketListener{
     fn set_client_in_room_reconnect(&mut self, client: ClientReference, room_code: RoomCode, room_client_id: RoomClientID){
 
         let sender = &client.sender(self).clone();
+        let address = client.address(self).ip();
         let Some(room) = self.get_room_mut(&room_code) else {
             client.send(self, ToClientPacket::RejectJoin { reason: RejectJoinReason::RoomDoesntExist });
             return
         };
+        if room.access().is_banned(address) || room.access().is_client_id_banned(room_client_id) {
+            client.send(self, ToClientPacket::RejectJoin { reason: RejectJoinReason::Banned });
+            return
+        }
         match room.rejoin_client(sender, room_client_id) {
             Ok(JoinRoomClientResult { id: room_client_id, in_game, spectator }) => {
                 sender.send(ToClientPacket::AcceptJoin { room_code, in_game, player_id: room_client_id, spectator });
@@ -147,8 +199,8 @@ impl WebsocketListener{
 
 
 
-    fn generate_roomcode(&self)->Option<RoomCode>{
-        let start = random::<u16>() as usize;
+    fn generate_roomcode(&mut self)->Option<RoomCode>{
+        let start = self.rng.random::<u16>() as usize;
         (start..=usize::MAX)
             .chain(0..start)
             .find(
@@ -158,7 +210,7 @@ impl WebsocketListener{
     pub(super) fn create_room(&mut self) -> Option<RoomCode>{
         let room_code = self.generate_roomcode()?;
 
-        self.rooms.insert(room_code, Box::new(Room::new()));
+        self.rooms.insert(room_code, Box::new(Room::new(SmallRng::from_rng(&mut self.rng))));
         Some(room_code)
     }
     pub(super) fn delete_room(&mut self, room_code: RoomCode){
@@ -175,17 +227,16 @@ impl WebsocketListener{
     }
 
     
+    /// Ticks once every real second. How much time passes in each tick is up to the listener's clock and speed.
     pub fn start_tick(listener: Arc<Mutex<Self>>) {
         const DESIRED_FRAME_TIME: Duration = Duration::from_secs(1);
 
         tokio::spawn(async move {
-            let mut frame_start_time = tokio::time::Instant::now();
             loop {
-                let delta_time = frame_start_time.elapsed();
-                frame_start_time = tokio::time::Instant::now();
+                let frame_start_time = tokio::time::Instant::now();
 
                 if let Ok(mut listener) = listener.lock() {
-                    listener.tick(delta_time);                  
+                    listener.tick();
                 } else { 
                     return;
                 }
@@ -196,6 +247,10 @@ impl WebsocketListener{
     }
 
 
+    fn is_banned_from_server(&self, client: &ClientReference) -> bool {
+        self.ban_list.is_banned(client.address(self).ip())
+    }
+
     fn validate_client(&self, addr: &SocketAddr)->Result<ClientReference,ValidateClientError>{
         let Some(client) = ClientReference::new(addr, self) else {return Err(ValidateClientError::ClientDoesntExist)};
         if let Err(GetRoomError::RoomDoesntExist) = client.get_room(self) {return Err(ValidateClientError::InRoomThatDoesntExist)};
@@ -0,0 +1,310 @@
+mod kit;
+#[path = "kit/night_order.rs"]
+mod night_order;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use night_order::{assert_night_order_independent, resolve_night};
+use mafia_server::game::{
+    components::night_visits::Visits,
+    event::registry::{EventListener, ListenerOrder},
+    modifiers::{
+        hidden_whispers::HiddenWhispers, no_death_cause::NoDeathCause, night_1_investigative_only::Night1InvestigativeOnly,
+        obscured_graves::ObscuredGraves, skip_day_1::SkipDay1, ModifierState
+    },
+    prelude::*,
+    settings::Settings
+};
+
+/// A few modifiers with hooks, enabled out of the order they are declared in
+fn with_modifiers() -> Settings {
+    let mut settings = Settings::default();
+    settings.modifiers.set_modifier(ModifierState::NoDeathCause(NoDeathCause));
+    settings.modifiers.set_modifier(ModifierState::ObscuredGraves(ObscuredGraves));
+    settings.modifiers.set_modifier(ModifierState::Night1InvestigativeOnly(Night1InvestigativeOnly));
+    settings.modifiers.set_modifier(ModifierState::SkipDay1(SkipDay1));
+    settings.modifiers.set_modifier(ModifierState::HiddenWhispers(HiddenWhispers));
+    settings
+}
+
+/// Every event's listeners, in the order they run
+#[test]
+fn listener_order() {
+    let settings = with_modifiers();
+    kit::scenario!(game in Night 1 with settings where
+        _townie: Villager,
+        _mafia: Mafioso
+    );
+
+    macro_rules! assert_listener_order {
+        ($($event:ty => [$($listener:literal),* $(,)?]),* $(,)?) => {$(
+            assert_eq!(
+                game.event_registry.listener_names::<$event>(),
+                Vec::<&str>::from([$($listener),*]),
+                "{} listeners", stringify!($event)
+            );
+        )*};
+    }
+
+    assert_listener_order! {
+        OnMidnight => [
+            "NightTrace::on_midnight_step_start",
+            "Detained::on_midnight",
+            "Poison::on_midnight",
+            "PuppeteerMarionette::on_midnight",
+            "MafiaRecruits::on_midnight",
+            "Night1InvestigativeOnly::on_midnight",
+            "Mafia::on_midnight",
+            "PlayerReference::on_midnight",
+            "Abilities::on_midnight",
+            "FragileVestsComponent::on_midnight",
+            "Guard::on_midnight",
+            "NightTrace::on_midnight_step_end",
+        ],
+        OnPhaseStart => [
+            "BlockedComponent::on_phase_start",
+            "Abilities::on_phase_start",
+            "ForfeitNominationVote::on_phase_start",
+            "Detained::on_phase_start",
+            "VerdictsToday::on_phase_start",
+            "Controllers::on_phase_start",
+            "SkipDay1::on_phase_start",
+            // Must go before CallWitness
+            "Silenced::on_phase_start",
+            "CallWitness::on_phase_start",
+            "Game::on_phase_start",
+            "FastForwardComponent::on_phase_start",
+            "Ascend::on_phase_start",
+        ],
+        OnGraveAdded => [
+            "Abilities::on_grave_added",
+            "NoDeathCause::on_grave_added",
+            "ObscuredGraves::on_grave_added",
+            "Graves::on_grave_added",
+        ],
+        OnWhisper => [
+            "ChatComponent::on_whisper",
+            "HiddenWhispers::on_whisper",
+            "Abilities::on_whisper",
+        ],
+        BeforePhaseEnd => [
+            "VerdictsToday::before_phase_end",
+            "Abilities::before_phase_end",
+            "Inactivity::before_phase_end",
+        ],
+        OnAbilityCreation => [
+            "RoleComponent::on_ability_creation",
+            "Abilities::on_ability_creation",
+        ],
+        OnAbilityDeletion => [
+            "Abilities::on_ability_deletion",
+        ],
+        OnAbilityEdit => [
+            "RoleComponent::on_ability_edit",
+        ],
+        OnAddInsider => [
+            "Abilities::on_add_insider",
+            "PuppeteerMarionette::on_add_insider",
+            "Mafia::on_add_insider",
+            "MafiaRecruits::on_add_insider",
+        ],
+        OnRemoveInsider => [
+            "Abilities::on_remove_insider",
+            "PuppeteerMarionette::on_remove_insider",
+            "Cult::on_remove_insider",
+            "Mafia::on_remove_insider",
+            "MafiaRecruits::on_remove_insider",
+        ],
+        OnAnyDeath => [
+            "Mafia::on_any_death",
+            "Cult::on_any_death",
+            "Abilities::on_any_death",
+            "DeadCanStillPlayMessage::on_any_death",
+        ],
+        OnConcealRole => [
+            "Abilities::on_conceal_role",
+            "InsiderGroups::on_conceal_role",
+        ],
+        OnControllerChanged => [
+            "Controllers::send_controller_to_client",
+            "OnControllerSelectionChanged::on_controller_changed",
+        ],
+        OnControllerInputReceived => [
+            "Controllers::on_controller_input_received",
+        ],
+        OnControllerSelectionChanged => [
+            "Mafia::on_controller_selection_changed",
+            "Abilities::on_controller_selection_changed",
+        ],
+        OnConvert => [
+            "SynopsisTracker::on_convert",
+        ],
+        OnGameEnding => [
+            "Game::on_game_ending",
+        ],
+        OnGameStart => [
+            "Mafia::on_game_start",
+            "Cult::on_game_start",
+            "EnfranchiseComponent::on_game_start",
+            "ForfeitNominationVote::on_game_start",
+        ],
+        OnPlayerPossessed => [
+            "Abilities::on_player_possessed",
+            "Mafia::on_player_possessed",
+        ],
+        OnPlayerRoleblocked => [
+            "Abilities::on_player_roleblocked",
+            "Mafia::on_player_roleblocked",
+            "BlockedComponent::on_player_roleblocked",
+        ],
+        OnRoleSwitch => [
+            "RevealedPlayersComponent::on_role_switch",
+            "Cult::on_role_switch",
+            "Mafia::on_role_switch",
+            "SynopsisTracker::on_role_switch",
+            "Abilities::on_role_switch",
+        ],
+        OnTick => [
+            "Controllers::on_tick",
+            "Ascend::on_tick",
+            "HideVotesMessage::on_tick",
+        ],
+        OnVisitWardblocked => [
+            "Abilities::on_visit_wardblocked",
+            "Mafia::on_visit_wardblocked",
+            "BlockedComponent::on_visit_wardblocked",
+        ],
+        OnValidatedControllerInputReceived => [
+            "Abilities::on_validated_ability_input_received",
+            "ForwardMessages::on_validated_ability_input_received",
+            "NominationController::on_validated_ability_input_received",
+            "ChatComponent::on_validated_ability_input_received",
+            "CallWitness::on_validated_ability_input_received",
+            "VoteHistory::on_validated_ability_input_received",
+            "Inactivity::on_validated_ability_input_received",
+        ],
+    }
+}
+
+#[test]
+fn disabled_modifiers_dont_listen() {
+    kit::scenario!(game in Night 1 where
+        _townie: Villager,
+        _mafia: Mafioso
+    );
+
+    assert_eq!(
+        game.event_registry.listener_names::<OnGraveAdded>(),
+        vec!["Abilities::on_grave_added", "Graves::on_grave_added"]
+    );
+    assert_eq!(
+        game.event_registry.listener_names::<OnWhisper>(),
+        vec!["ChatComponent::on_whisper", "Abilities::on_whisper"]
+    );
+}
+
+static PHASES_STARTED: AtomicUsize = AtomicUsize::new(0);
+
+fn count_phase_start(_game: &mut Game, _event: &OnPhaseStart, _fold: &mut (), _priority: ()) {
+    PHASES_STARTED.fetch_add(1, Ordering::Relaxed);
+}
+
+#[test]
+fn listeners_can_subscribe_after_game_creation() {
+    kit::scenario!(game in Night 1 where
+        _townie: Villager,
+        _mafia: Mafioso
+    );
+
+    game.event_registry.subscribe(EventListener::new("count_phase_start", count_phase_start).order(ListenerOrder::First));
+    assert_eq!(game.event_registry.listener_names::<OnPhaseStart>().first(), Some(&"count_phase_start"));
+
+    game.next_phase();
+    assert_eq!(PHASES_STARTED.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn transporter_night_is_order_independent() {
+    assert_night_order_independent(16, |order| {
+        kit::scenario!(game in Night 2 seeded 0 where
+            _godfather: Godfather,
+            trans: Transporter,
+            vigi: Vigilante,
+            escort: Escort,
+            town1: Detective,
+            town2: Detective
+        );
+        trans.send_ability_input_two_player_typical(town1, town2);
+        vigi.send_ability_input_player_list_typical(town1);
+        escort.send_ability_input_player_list_typical(town2);
+
+        resolve_night(&mut game, order)
+    });
+}
+
+#[test]
+fn busy_night_is_order_independent() {
+    assert_night_order_independent(16, |order| {
+        kit::scenario!(game in Night 2 seeded 0 where
+            mafioso: Mafioso,
+            consort: Consort,
+            framer: Framer,
+            witch: Witch,
+            serial_killer: SerialKiller,
+            transporter: Transporter,
+            lookout: Lookout,
+            tracker: Tracker,
+            detective: Detective,
+            doctor: Doctor,
+            bodyguard: Bodyguard,
+            escort: Escort,
+            vigilante: Vigilante,
+            villager: Villager
+        );
+        mafioso.send_ability_input_player_list_typical(villager);
+        consort.send_ability_input_player_list_typical(detective);
+        framer.send_ability_input_player_list_typical(doctor);
+        witch.send_ability_input_two_player_typical(vigilante, mafioso);
+        serial_killer.send_ability_input_player_list_typical(lookout);
+        transporter.send_ability_input_two_player_typical(villager, lookout);
+        lookout.send_ability_input_player_list_typical(villager);
+        tracker.send_ability_input_player_list_typical(serial_killer);
+        detective.send_ability_input_player_list_typical(framer);
+        doctor.send_ability_input_player_list_typical(villager);
+        bodyguard.send_ability_input_player_list_typical(lookout);
+        escort.send_ability_input_player_list_typical(serial_killer);
+        vigilante.send_ability_input_player_list_typical(consort);
+
+        resolve_night(&mut game, order)
+    });
+}
+
+fn block_unless_attacked(_game: &mut Game, _event: &OnMidnight, fold: &mut OnMidnightFold, priority: OnMidnightPriority) {
+    if priority != OnMidnightPriority::Kill {return}
+    let Some(player) = Visits::into_iter(fold).next().map(|visit| visit.target) else {return};
+    if !player.night_attacked(fold) {
+        player.set_night_blocked(fold, true);
+    }
+}
+fn attack_first_target(_game: &mut Game, _event: &OnMidnight, fold: &mut OnMidnightFold, priority: OnMidnightPriority) {
+    if priority != OnMidnightPriority::Kill {return}
+    let Some(player) = Visits::into_iter(fold).next().map(|visit| visit.target) else {return};
+    player.set_night_attacked(fold, true);
+}
+
+#[test]
+#[should_panic(expected = "Night outcome depends on listener order")]
+fn order_dependent_listeners_are_flagged() {
+    assert_night_order_independent(16, |order| {
+        kit::scenario!(game in Night 1 seeded 0 where
+            townie: Villager,
+            detective: Detective,
+            _mafia: Mafioso
+        );
+        detective.send_ability_input_player_list_typical(townie);
+        game.event_registry.subscribe(EventListener::new("block_unless_attacked", block_unless_attacked));
+        game.event_registry.subscribe(EventListener::new("attack_first_target", attack_first_target));
+
+        resolve_night(&mut game, order)
+    });
+}
@@ -0,0 +1,246 @@
+//! Plays whole games with random settings and random legal inputs, checking invariants as they go.
+//! The random games take about 30 seconds in a debug build, so they only run with `cargo test --test fuzz -- --ignored`.
+//! Every game is seeded, so a failure can be replayed with `FUZZ_SEED=<seed> FUZZ_GAMES=1 cargo test --test fuzz -- --ignored`.
+//! Set `FUZZ_GAMES` higher to look for new failures. Use a debug build, where references check they are in bounds.
+
+use std::{panic::{catch_unwind, AssertUnwindSafe}, time::Duration};
+
+use mafia_server::{
+    game::{
+        components::{graves::{grave::GraveDeathCause, grave_reference::GraveReference}, night_visits::Visits},
+        controllers::*,
+        modifiers::{ModifierID, ModifierSettings},
+        phase::{PhaseState, PhaseType},
+        player::PlayerReference,
+        role::{kira::KiraGuess, Role},
+        role_list::{
+            RoleList, RoleOutline, RoleOutlineOption, RoleOutlineOptionInsiderGroups,
+            RoleOutlineOptionRoles, RoleOutlineOptionWinCondition, RoleSet
+        },
+        settings::Settings,
+        test::mock_game,
+        Game
+    },
+    packet::ToServerPacket,
+    room::RoomState,
+    vec_map::VecMap
+};
+use rand::{rngs::SmallRng, seq::{IndexedRandom, IteratorRandom}, Rng, SeedableRng};
+
+const DEFAULT_GAMES: u64 = 4;
+/// A phase is skipped every tick, so this is more than enough to reach the maximum day
+const MAX_TICKS: u32 = 4096;
+
+const ROLE_SETS: [RoleSet; 14] = [
+    RoleSet::Any,
+    RoleSet::Town, RoleSet::TownCommon, RoleSet::TownInvestigative, RoleSet::TownProtective, RoleSet::TownKilling, RoleSet::TownSupport,
+    RoleSet::Mafia, RoleSet::MafiaSupport, RoleSet::MafiaKilling,
+    RoleSet::Cult, RoleSet::Fiends, RoleSet::Neutral, RoleSet::Minions,
+];
+
+#[test]
+#[ignore = "slow, run with --ignored"]
+fn random_games_hold_invariants() {
+    let first_seed = env_u64("FUZZ_SEED").unwrap_or(0);
+    let games = env_u64("FUZZ_GAMES").unwrap_or(DEFAULT_GAMES);
+
+    let failures: Vec<String> = (first_seed..first_seed.saturating_add(games))
+        .filter_map(|seed| match catch_unwind(AssertUnwindSafe(|| play_game(seed))) {
+            Ok(Ok(_)) => None,
+            Ok(Err(err)) => Some(format!("seed {seed}: {err}")),
+            Err(panic) => Some(format!(
+                "seed {seed}: panicked: {}",
+                panic.downcast_ref::<String>().map(String::as_str)
+                    .or_else(|| panic.downcast_ref::<&str>().copied())
+                    .unwrap_or("unknown panic")
+            )),
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "{} of {games} games failed:\n{}", failures.len(), failures.join("\n"));
+}
+
+#[test]
+fn replaying_a_seed_plays_the_same_game() {
+    let first_seed = env_u64("FUZZ_SEED").unwrap_or(0);
+
+    let (seed, first) = (first_seed..)
+        .find_map(|seed| play_game(seed).ok().flatten().map(|game| (seed, record(&game))))
+        .expect("Some seed makes a game");
+    let second = play_game(seed).ok().flatten().map(|game| record(&game));
+
+    assert!(second.as_ref() == Some(&first), "seed {seed} played differently the second time");
+}
+
+/// Everything players could see by the end of the game
+fn record(game: &Game) -> Vec<String> {
+    PlayerReference::all_players(game)
+        .map(|player| format!(
+            "{} {:?} alive: {} {:?}",
+            player.name(game), player.role(game), player.alive(game),
+            player.chat_messages(game).iter().map(|message| &message.variant).collect::<Vec<_>>()
+        ))
+        .chain(GraveReference::all_graves(game).map(|grave| format!("{:?}", grave.deref(game))))
+        .collect()
+}
+
+fn env_u64(name: &str) -> Option<u64> {
+    std::env::var(name).ok().and_then(|value| value.parse().ok())
+}
+
+/// The game once it's over, or None if the seed didn't make one
+fn play_game(seed: u64) -> Result<Option<Game>, String> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+
+    let Some(mut game) = random_game(&mut rng, seed) else {
+        // Some random role lists can't make a game, which is fine
+        return Ok(None);
+    };
+
+    let mut deactivated_at_night_start = Vec::new();
+    for _ in 0..MAX_TICKS {
+        if !game.ticking {break}
+
+        send_random_inputs(&mut game, &mut rng);
+
+        let phase_before = game.current_phase().phase();
+        if phase_before == PhaseType::Night {
+            deactivated_at_night_start = PlayerReference::all_players(&game)
+                .filter(|player| player.ability_deactivated_from_death(&game))
+                .collect();
+        }
+
+        let _ = game.tick(Duration::from_secs(3600));
+
+        if phase_before == PhaseType::Night && let PhaseState::Obituary { last_night } = game.current_phase() {
+            let acted = Visits::into_iter(last_night)
+                .find(|visit| !visit.indirect && deactivated_at_night_start.contains(&visit.visitor));
+            if let Some(visit) = acted {
+                return Err(format!(
+                    "dead {:?} visited during night {}: {visit:?}",
+                    visit.visitor.role(&game), game.day_number().saturating_sub(1)
+                ));
+            }
+        }
+    }
+
+    if !game.game_is_over() && game.day_number() != u8::MAX {
+        return Err(format!("the game didn't end after {MAX_TICKS} phases, it's {:?} {}", game.current_phase().phase(), game.day_number()));
+    }
+    Ok(Some(game))
+}
+
+fn random_game(rng: &mut SmallRng, seed: u64) -> Option<Game> {
+    let players: u8 = rng.random_range(4..=15);
+
+    let role_list = (0..players)
+        .map(|_| {
+            let options = (0..rng.random_range(1..=2))
+                .filter_map(|_| ROLE_SETS.choose(rng))
+                .map(|role_set| RoleOutlineOption {
+                    roles: RoleOutlineOptionRoles::RoleSet { role_set: role_set.clone() },
+                    insider_groups: RoleOutlineOptionInsiderGroups::RoleDefault,
+                    win_condition: RoleOutlineOptionWinCondition::RoleDefault,
+                    player_pool: Default::default(),
+                })
+                .collect::<Vec<_>>();
+            vec1::Vec1::try_from_vec(options).ok().map(|options| RoleOutline { options })
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let mut modifiers = ModifierSettings::default();
+    for modifier in ModifierID::values() {
+        if rng.random_bool(0.2) {
+            modifiers.set_modifier(modifier.default_state());
+        }
+    }
+
+    let settings = Settings {
+        random_seed: Some(seed),
+        role_list: RoleList(role_list),
+        enabled_roles: Role::values().into_iter().collect(),
+        modifiers,
+        ..Default::default()
+    };
+
+    let (game, _) = mock_game(settings, players).ok()?;
+    (!game.game_is_over()).then_some(game)
+}
+
+/// Every player, dead or alive, changes some of the controllers they're allowed to use
+fn send_random_inputs(game: &mut Game, rng: &mut SmallRng) {
+    for player in PlayerReference::all_players(game) {
+        let controllers = game.controllers.controller_parameters_allowed_to_player(player);
+        for (id, parameters) in controllers.controller_parameters().iter() {
+            if !rng.random_bool(0.25) {continue}
+
+            let Some(selection) = random_selection(game, parameters, rng) else {continue};
+            game.on_player_message(0, player, ToServerPacket::ControllerInput {
+                controller_input: ControllerInput::new(id.clone(), selection)
+            });
+        }
+    }
+}
+
+/// A selection the controller's parameters say is valid, if one was found
+fn random_selection(game: &Game, parameters: &ControllerParameters, rng: &mut SmallRng) -> Option<ControllerSelection> {
+    let players: Vec<PlayerReference> = PlayerReference::all_players(game).collect();
+
+    let selection: ControllerSelection = match parameters.available() {
+        AvailableControllerSelection::Unit(_) => UnitSelection.into(),
+        AvailableControllerSelection::Boolean(_) => BooleanSelection(rng.random()).into(),
+        AvailableControllerSelection::PlayerList(available) => {
+            let max = available.max_players.map_or(available.available_players.count(), usize::from);
+            let count = rng.random_range(0..=max.min(available.available_players.count()));
+            PlayerListSelection(available.available_players.iter().copied().choose_multiple(rng, count)).into()
+        },
+        AvailableControllerSelection::TwoPlayerOption(available) => TwoPlayerOptionSelection(
+            available.available_first_players.iter().choose(rng).copied()
+                .zip(available.available_second_players.iter().choose(rng).copied())
+                .filter(|_| !available.can_choose_none || rng.random_bool(0.8))
+        ).into(),
+        AvailableControllerSelection::RoleList(available) => {
+            let max = available.max_roles.map_or(available.available_roles.count(), usize::from);
+            let count = rng.random_range(0..=max.min(available.available_roles.count()));
+            RoleListSelection(available.available_roles.iter().copied().choose_multiple(rng, count)).into()
+        },
+        AvailableControllerSelection::TwoRoleOption(available) => TwoRoleOptionSelection(
+            available.available_roles.iter().choose(rng).copied().flatten(),
+            available.available_roles.iter().choose(rng).copied().flatten()
+        ).into(),
+        AvailableControllerSelection::TwoRoleOutlineOption(available) => TwoRoleOutlineOptionSelection(
+            available.0.iter().choose(rng).copied().flatten(),
+            available.0.iter().choose(rng).copied().flatten()
+        ).into(),
+        AvailableControllerSelection::GraveDeathCauses(_) => GraveDeathCausesSelection(
+            [GraveDeathCause::Execution, GraveDeathCause::Suicide, GraveDeathCause::Role(random_role(rng))]
+                .into_iter()
+                .filter(|_| rng.random_bool(0.5))
+                .collect()
+        ).into(),
+        AvailableControllerSelection::String(_) => StringSelection(
+            ["", "hi", "I'm the detective", "vote them"].choose(rng).copied().unwrap_or_default().to_owned()
+        ).into(),
+        AvailableControllerSelection::Integer(available) => IntegerSelection(
+            rng.random_range(available.min..=available.max.max(available.min))
+        ).into(),
+        AvailableControllerSelection::Kira(available) => KiraSelection::new(
+            players.iter()
+                .copied()
+                .filter(|player| player.alive(game))
+                .choose_multiple(rng, available.count_must_guess.into())
+                .into_iter()
+                .map(|player| (player, [KiraGuess::None, KiraGuess::NonTown, KiraGuess::Role(random_role(rng))].choose(rng).cloned().unwrap_or_default()))
+                .collect::<VecMap<_, _>>()
+        ).into(),
+        AvailableControllerSelection::ChatMessage(_) => ChatMessageSelection(
+            rng.random_bool(0.5).then(|| rng.random_range(0..16))
+        ).into(),
+    };
+
+    parameters.validate_selection(game, &selection).then_some(selection)
+}
+
+fn random_role(rng: &mut SmallRng) -> Role {
+    Role::values().iter().choose(rng).copied().unwrap_or(Role::Villager)
+}
@@ -0,0 +1,178 @@
+mod kit;
+
+use kit::assert_contains;
+
+use std::time::Duration;
+
+use mafia_server::{
+    game::{
+        components::{
+            graves::grave_reference::GraveReference,
+            moderation::{Moderation, ModerationAction},
+            pause::Pause
+        },
+        phase::{PhaseStateMachine, PhaseType},
+        prelude::*,
+        settings::PhaseTimeSettings
+    },
+    packet::ToServerPacket,
+    room::RoomState
+};
+
+#[test]
+fn paused_game_doesnt_advance() {
+    kit::scenario!(game in Nomination 2 where
+        detective: Detective,
+        _townie: Villager,
+        goon: Goon
+    );
+    Pause::pause(&mut game);
+
+    let _ = game.tick(Duration::from_secs(60 * 60));
+    assert_eq!(game.current_phase().phase(), PhaseType::Nomination);
+
+    detective.vote_for_player(goon);
+    assert_eq!(detective.player_ref().chosen_vote(&game), None);
+
+    Pause::resume(&mut game, None);
+    detective.vote_for_player(goon);
+    assert_eq!(detective.player_ref().chosen_vote(&game), Some(goon.player_ref()));
+}
+
+#[test]
+fn resume_countdown() {
+    kit::scenario!(game in Discussion 2 where
+        _detective: Detective,
+        _townie: Villager,
+        _goon: Goon
+    );
+    Pause::pause(&mut game);
+    Pause::resume(&mut game, Some(5));
+
+    let _ = game.tick(Duration::from_secs(3));
+    assert!(Pause::is_paused(&game));
+
+    let _ = game.tick(Duration::from_secs(3));
+    assert!(!Pause::is_paused(&game));
+    assert_eq!(game.current_phase().phase(), PhaseType::Discussion);
+}
+
+#[test]
+fn host_adjusts_phase_time() {
+    kit::scenario!(game in Discussion 2 where
+        _detective: Detective,
+        _townie: Villager,
+        _goon: Goon
+    );
+    let discussion = Duration::from_secs(game.settings.phase_times.discussion.into());
+
+    PhaseStateMachine::adjust_time_remaining(&mut game, 60);
+    let _ = game.tick(discussion);
+    let _ = game.tick(Duration::from_secs(1));
+    assert_eq!(game.current_phase().phase(), PhaseType::Discussion);
+
+    PhaseStateMachine::adjust_time_remaining(&mut game, -60);
+    let _ = game.tick(Duration::from_secs(1));
+    assert_eq!(game.current_phase().phase(), PhaseType::Nomination);
+}
+
+#[test]
+fn adjusting_phase_time_reports_the_change_made() {
+    kit::scenario!(game in Discussion 2 where
+        detective: Detective,
+        _townie: Villager,
+        _goon: Goon
+    );
+    let longest = i32::from(u16::MAX);
+
+    // Capped at the longest phase time, then taken down to nothing
+    PhaseStateMachine::adjust_time_remaining(&mut game, i32::MAX);
+    PhaseStateMachine::adjust_time_remaining(&mut game, i32::MIN);
+    assert_contains!(detective.get_messages(), ChatMessageVariant::PhaseTimeAdjusted { seconds: -longest });
+
+    PhaseStateMachine::adjust_time_remaining(&mut game, i32::MAX);
+    assert_contains!(detective.get_messages(), ChatMessageVariant::PhaseTimeAdjusted { seconds: longest });
+}
+
+#[test]
+fn host_is_told_when_phase_times_are_invalid() {
+    kit::scenario!(game in Discussion 2 where
+        host: Detective,
+        _townie: Villager,
+        _goon: Goon
+    );
+    let bad_percentages = PhaseTimeSettings { day_percentages: vec![0], ..PhaseTimeSettings::default() };
+
+    game.on_player_message(0, host.player_ref(), ToServerPacket::HostSetPhaseTimes { phase_time_settings: bad_percentages });
+    assert_contains!(host.get_messages(), ChatMessageVariant::InvalidPhaseTimes);
+}
+
+#[test]
+fn host_changes_phase_times_mid_game() {
+    kit::scenario!(game in Discussion 2 where
+        _detective: Detective,
+        _townie: Villager,
+        _goon: Goon
+    );
+
+    let zero_times = PhaseTimeSettings {
+        obituary: 0, discussion: 0, nomination: 0, night: 0, dusk: 0,
+        ..PhaseTimeSettings::default()
+    };
+    assert!(!PhaseStateMachine::set_phase_times(&mut game, zero_times));
+
+    let mut bad_percentages = PhaseTimeSettings { day_percentages: vec![0], ..PhaseTimeSettings::default() };
+    assert!(!PhaseStateMachine::set_phase_times(&mut game, bad_percentages.clone()));
+    bad_percentages.day_percentages = vec![100];
+    bad_percentages.endgame.percentage = u16::MAX;
+    assert!(!PhaseStateMachine::set_phase_times(&mut game, bad_percentages));
+    assert_eq!(game.settings.phase_times.endgame.percentage, PhaseTimeSettings::default().endgame.percentage);
+
+    let phase_times = PhaseTimeSettings { nomination: 5, ..PhaseTimeSettings::default() };
+    assert!(PhaseStateMachine::set_phase_times(&mut game, phase_times));
+    assert_eq!(game.settings.phase_times.nomination, 5);
+
+    game.next_phase();
+    assert_eq!(game.current_phase().phase(), PhaseType::Nomination);
+    let _ = game.tick(Duration::from_secs(6));
+    let _ = game.tick(Duration::from_secs(1));
+    assert_ne!(game.current_phase().phase(), PhaseType::Nomination);
+}
+
+#[test]
+fn host_modkills_player() {
+    kit::scenario!(game in Discussion 2 where
+        _detective: Detective,
+        townie: Villager,
+        _goon: Goon
+    );
+
+    assert!(Moderation::modkill(&mut game, townie.player_ref()));
+    assert!(!townie.alive());
+    assert!(!Moderation::modkill(&mut game, townie.player_ref()));
+
+    let Some(grave) = GraveReference::all_graves(&game).next() else { panic!("No grave was made") };
+    let GraveInformation::Normal { death_causes, .. } = &grave.deref(&game).information else { panic!("Grave was obscured") };
+    assert_eq!(death_causes, &vec![GraveDeathCause::Modkill]);
+
+    let Some(record) = Moderation::records(&game).first() else { panic!("Modkill wasn't recorded") };
+    assert_eq!(record.action, ModerationAction::Modkill { player: townie.player_ref() });
+}
+
+#[test]
+fn host_force_reveals_player() {
+    kit::scenario!(game in Discussion 2 where
+        detective: Detective,
+        townie: Villager,
+        goon: Goon
+    );
+
+    assert!(Moderation::force_reveal(&mut game, goon.player_ref()));
+
+    assert!(detective.player_ref().revealed_players(&game).contains(&goon.player_ref()));
+    assert!(townie.player_ref().revealed_players(&game).contains(&goon.player_ref()));
+    assert_eq!(
+        Moderation::records(&game).first().map(|record| record.action.clone()),
+        Some(ModerationAction::ForceReveal { player: goon.player_ref(), role: Role::Goon })
+    );
+}
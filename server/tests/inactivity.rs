@@ -0,0 +1,118 @@
+mod kit;
+
+use std::net::SocketAddr;
+
+use mafia_server::{
+    game::{
+        components::inactivity::Inactivity,
+        prelude::*,
+        settings::{InactivityAction, InactivitySettings},
+    },
+    packet::{ToClientPacket, ToServerPacket},
+    room::RoomState,
+    websocket_connections::connection::Connection
+};
+use tokio::sync::mpsc;
+
+#[test]
+fn idle_players_are_not_tracked_by_default() {
+    kit::scenario!(game in Discussion 2 where
+        _detective: Detective,
+        townie: Villager,
+        _goon: Goon
+    );
+
+    game.skip_to(PhaseType::Discussion, 5);
+    game.next_phase();
+    assert_eq!(Inactivity::idle_phases(&game, townie.player_ref()), 0);
+    assert!(!townie.get_messages().iter().any(|message| matches!(message, ChatMessageVariant::InactivityWarning { .. })));
+}
+
+#[test]
+fn idle_player_is_warned_then_killed() {
+    kit::scenario!(game in Discussion 2 where
+        detective: Detective,
+        townie: Villager,
+        goon: Goon
+    );
+    game.settings.inactivity = InactivitySettings { warning_after: 2, action_after: 3, action: InactivityAction::Kill };
+
+    detective.send_message("Hello");
+    game.next_phase();
+    assert_eq!(Inactivity::idle_phases(&game, townie.player_ref()), 1);
+    assert_eq!(Inactivity::idle_phases(&game, goon.player_ref()), 1);
+    assert_eq!(Inactivity::idle_phases(&game, detective.player_ref()), 0);
+
+    // Voting counts as activity for the next discussion
+    goon.vote_for_player(detective);
+    game.skip_to(PhaseType::Discussion, 3);
+    game.next_phase();
+    assert!(townie.get_messages().contains(&ChatMessageVariant::InactivityWarning { phases_left: Some(1) }));
+    assert_eq!(Inactivity::idle_phases(&game, goon.player_ref()), 0);
+    assert!(townie.alive());
+
+    detective.send_message("Where is everyone?");
+    goon.send_message("Not sure");
+    game.skip_to(PhaseType::Discussion, 4);
+    game.next_phase();
+    assert!(!townie.alive());
+    assert!(detective.alive());
+    assert!(goon.alive());
+}
+
+#[test]
+fn idle_player_is_replaced_by_waiting_spectator() {
+    kit::scenario!(game in Discussion 2 where
+        detective: Detective,
+        townie: Villager,
+        goon: Goon
+    );
+    game.settings.inactivity = InactivitySettings { warning_after: 1, action_after: 1, action: InactivityAction::Replace };
+
+    let (tx, mut receiver) = mpsc::unbounded_channel();
+    let sender = Connection::new(tx, SocketAddr::from(([127, 0, 0, 1], 1))).sender();
+    let Ok(joined) = game.join_client(&sender) else { panic!("Failed to join as spectator") };
+    game.initialize_client(joined.id, &sender);
+    game.on_client_message(&sender, joined.id, ToServerPacket::SetWaitingToReplace { waiting: true });
+    while receiver.try_recv().is_ok() {}
+
+    detective.send_message("Hello");
+    goon.send_message("Hi");
+    game.next_phase();
+
+    assert!(townie.alive());
+    assert!(townie.get_messages().contains(&ChatMessageVariant::PlayerRemovedForInactivity { player: townie.player_ref(), replaced: true }));
+
+    let mut player_index = None;
+    while let Ok(packet) = receiver.try_recv() {
+        if let ToClientPacket::YourPlayerIndex { player_index: index } = packet {
+            player_index = Some(index);
+        }
+    }
+    assert_eq!(player_index, Some(townie.index()));
+}
+
+#[test]
+fn idle_player_is_kept_until_a_spectator_is_waiting() {
+    kit::scenario!(game in Discussion 2 where
+        detective: Detective,
+        townie: Villager,
+        goon: Goon
+    );
+    game.settings.inactivity = InactivitySettings { warning_after: 1, action_after: 1, action: InactivityAction::Replace };
+
+    // This spectator never says it wants to play
+    let (tx, mut receiver) = mpsc::unbounded_channel();
+    let sender = Connection::new(tx, SocketAddr::from(([127, 0, 0, 1], 1))).sender();
+    let Ok(joined) = game.join_client(&sender) else { panic!("Failed to join as spectator") };
+    game.initialize_client(joined.id, &sender);
+    while receiver.try_recv().is_ok() {}
+
+    detective.send_message("Hello");
+    goon.send_message("Hi");
+    game.next_phase();
+
+    assert!(townie.alive());
+    assert!(!townie.get_messages().iter().any(|message| matches!(message, ChatMessageVariant::PlayerRemovedForInactivity { .. })));
+    assert!(!std::iter::from_fn(|| receiver.try_recv().ok()).any(|packet| matches!(packet, ToClientPacket::YourPlayerIndex { .. })));
+}
@@ -1,4 +1,4 @@
-use mafia_server::game::{phase::{PhaseState, PhaseStateMachine, PhaseType}, Game};
+use mafia_server::game::{phase::{PhaseState, PhaseStateMachine, PhaseType}, phase_schedule::ScheduledPhase, Game};
 
 pub struct TestGame (*mut Game);
 
@@ -24,7 +24,7 @@ impl TestGame {
     /// Advance the game naturally, passing through all the phases, until the given day and phase is met.
     /// ### Panics:
     /// * When the supplied phase doesn't always happen, like Judgement.
-    /// * When the specified phase *cannot* happen, like Discussion 1.
+    /// * When the specified phase *cannot* happen, like Discussion 1 with the default phase schedule.
     /// * When the specified day and phase is in the past.
     /// * If this would take the game to a day past the maximum day
     pub fn skip_to(&mut self, phase: PhaseType, day_number: u8) -> &PhaseState {
@@ -33,9 +33,13 @@ impl TestGame {
             panic!("The only Briefing phase is Briefing 1. Tried to go to Briefing {day_number} from {:?} {}.", self.current_phase().phase(), self.day_number()); 
         }
     
-        // The only phases that occur day 1 are Briefing, Dusk and Night.
-        if day_number == 1 && !matches!(phase, PhaseType::Briefing | PhaseType::Dusk | PhaseType::Night) {
-            panic!("There is no {phase:?} 1. Tried to go to {phase:?} 1 from {:?} {}. The only phases that occur day 1 are Briefing, Dusk, & Night.", self.current_phase().phase(), self.day_number()); 
+        // Day 1 is Briefing and whatever the phase schedule has before the first obituary
+        let day_1_steps: Vec<ScheduledPhase> = self.phase_schedule().first_day.iter()
+            .copied()
+            .take_while(|step| *step != ScheduledPhase::Obituary)
+            .collect();
+        if day_number == 1 && phase != PhaseType::Briefing && !ScheduledPhase::from_phase(phase).is_some_and(|step| day_1_steps.contains(&step)) {
+            panic!("There is no {phase:?} 1. Tried to go to {phase:?} 1 from {:?} {}. The only phases that occur day 1 are Briefing & {day_1_steps:?}.", self.current_phase().phase(), self.day_number()); 
         }
 
         // If the phase & day is in the past
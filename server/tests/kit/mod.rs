@@ -14,7 +14,7 @@ pub struct TestScenario {
     pub players: Vec<PlayerReference>
 }
 
-#[allow(unused)]
+#[allow(unused, reason = "Each test only uses some of the macros")]
 macro_rules! scenario {
     ($game:ident in Briefing 1 $($tok:tt)*) => {
         kit::scenario!($game $($tok)*);
@@ -23,12 +23,25 @@ macro_rules! scenario {
         kit::scenario!($game $($tok)*);
         $game.skip_to(PhaseType::$phase, $day);
     };
-    ($game:ident where
+    ($game:ident seeded $seed:literal where $($tok:tt)*) => {
+        kit::scenario!(@create $game, Some($seed), $($tok)*);
+    };
+    ($game:ident with $settings:ident where $($tok:tt)*) => {
+        kit::scenario!(@create_with $game, $settings, $($tok)*);
+    };
+    ($game:ident where $($tok:tt)*) => {
+        kit::scenario!(@create $game, None, $($tok)*);
+    };
+    (@create $game:ident, $seed:expr, $($tok:tt)*) => {
+        kit::scenario!(@create_with $game, mafia_server::game::settings::Settings { random_seed: $seed, ..Default::default() }, $($tok)*);
+    };
+    (@create_with $game:ident, $settings:expr,
         $($name:ident: $role:ident),*
     ) => {
-        let mut scenario = kit::_init::create_basic_scenario(
+        let mut scenario = kit::_init::create_scenario(
             // vec![$(RoleState::$role($role::default())),*]
-            vec![$(Role::$role),*]
+            vec![$(Role::$role),*],
+            $settings
         );
 
         let game = &mut scenario.game;
@@ -45,20 +58,20 @@ macro_rules! scenario {
     }
 }
 
-#[allow(unused)]
+#[allow(unused, reason = "Each test only uses some of the macros")]
 macro_rules! assert_contains {
     ($container:expr, $value:expr) => {
         assert!($container.contains(&$value), "{}", format!("Expected {:#?} to contain {:?}", $container, $value));
     };
 }
-#[allow(unused)]
+#[allow(unused, reason = "Each test only uses some of the macros")]
 macro_rules! assert_not_contains {
     ($container:expr, $value:expr) => {
         assert!(!$container.contains(&$value), "{}", format!("Expected {:#?} not to contain {:?}", $container, $value))
     };
 }
 
-#[allow(unused)]
+#[allow(unused, reason = "Each test only uses some of the macros")]
 pub(crate) use {scenario, assert_contains, assert_not_contains};
 
 //Formats messages in a way where it's clear which phase each message was sent in
@@ -91,7 +104,9 @@ pub mod _init {
 
     use super::*;
 
-    pub fn create_basic_scenario(roles: Vec<Role>) -> TestScenario {
+    /// Gives each role its own outline and enables every role, keeping the rest of `settings`.
+    /// With a seed, the same roles are always given to the same players.
+    pub fn create_scenario(roles: Vec<Role>, settings: Settings) -> TestScenario {
         let mut role_list = Vec::new();
         for (i, role) in roles.iter().enumerate() {
             role_list.push(RoleOutline { options: 
@@ -107,7 +122,7 @@ pub mod _init {
         let (game, mut assignments) = match mock_game(Settings {
             role_list: RoleList(role_list),
             enabled_roles: Role::values().into_iter().collect(),
-            ..Default::default()
+            ..settings
         }, roles.len() as u8){
             Ok(game) => game,
             Err(err) => panic!("Failed to create game: {err:?}"),
@@ -119,9 +134,11 @@ pub mod _init {
         for player in players_out.iter_mut(){
             let role = roles.get(player.index() as usize)
                 .expect("test scenario assert");
+            // Each outline's player pool is only its own player, so prefer that player when roles repeat
             let found_player = *assignments
                 .iter()
-                .find(|(_,r)|r.role == *role)
+                .find(|(p,r)|r.role == *role && p.index() == player.index())
+                .or_else(|| assignments.iter().find(|(_,r)|r.role == *role))
                 .map(|(p,_)|p)
                 .expect("test scenario assert");
 
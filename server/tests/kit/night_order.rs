@@ -0,0 +1,123 @@
+use mafia_server::game::{
+    components::night_visits::Visits,
+    event::prelude::OnMidnight,
+    phase::PhaseState,
+    player::PlayerReference,
+    Game
+};
+use rand::{rngs::SmallRng, SeedableRng};
+
+use crate::kit::game::TestGame;
+
+/// The order midnight listeners run in when a night is resolved by [`resolve_night`]
+#[derive(Clone, Copy, Debug)]
+pub enum NightOrder {
+    /// The order they subscribed in, like a real game
+    Subscribed,
+    /// Listeners and abilities with the same order are shuffled with this seed
+    Shuffled(u64),
+}
+
+/// Everything a night decided, without the order it was decided in
+#[derive(Debug, PartialEq, Eq)]
+pub struct NightOutcome {
+    pub players: Vec<PlayerNightOutcome>,
+    pub visits: Vec<String>,
+}
+
+/// Lists are sorted, because listeners that run in a different order
+/// can send messages in a different order without anything being wrong
+#[derive(Debug, PartialEq, Eq)]
+pub struct PlayerNightOutcome {
+    pub player: PlayerReference,
+    pub role: String,
+    pub alive: bool,
+    pub attacked: bool,
+    pub blocked: bool,
+    pub upgraded_defense: String,
+    pub convert_role_to: String,
+    pub framed: bool,
+    pub appeared_visits: bool,
+    pub messages: Vec<String>,
+    pub grave_role: String,
+    pub grave_death_causes: Vec<String>,
+    pub grave_calling_cards: Vec<String>,
+    pub guarded_players: Vec<PlayerReference>,
+}
+
+/// Resolves the current night in the given order and records what happened.
+/// The game's rng is reseeded first so a random ability doesn't look order dependent,
+/// but two abilities drawing from it in the same priority still will.
+pub fn resolve_night(game: &mut TestGame, order: NightOrder) -> NightOutcome {
+    if let NightOrder::Shuffled(seed) = order {
+        game.event_registry.shuffle_listeners::<OnMidnight>(seed);
+    }
+    game.rng = SmallRng::seed_from_u64(0);
+    game.next_phase();
+
+    let game: &Game = game;
+    let PhaseState::Obituary { last_night } = game.current_phase() else {
+        panic!("Expected the night to be followed by an obituary, got {:?}", game.current_phase());
+    };
+
+    let players = PlayerReference::all_players(game)
+        .map(|player| {
+            let night = last_night.get(player);
+            PlayerNightOutcome {
+                player,
+                role: format!("{:?}", player.role(game)),
+                alive: player.alive(game),
+                attacked: night.attacked,
+                blocked: night.blocked,
+                upgraded_defense: format!("{:?}", night.upgraded_defense),
+                convert_role_to: format!("{:?}", night.convert_role_to.as_ref().map(|role| role.role())),
+                framed: night.framed,
+                appeared_visits: night.appeared_visits,
+                messages: sorted_debug(&night.messages),
+                grave_role: format!("{:?}", night.grave_role),
+                grave_death_causes: sorted_debug(&night.grave_death_causes),
+                grave_calling_cards: sorted_debug(&night.grave_calling_cards),
+                guarded_players: {
+                    let mut guarded = night.guarded_players.clone();
+                    guarded.sort_by_key(|player| player.index());
+                    guarded
+                },
+            }
+        })
+        .collect();
+
+    NightOutcome {
+        players,
+        visits: sorted_debug(&Visits::into_iter(last_night).collect::<Vec<_>>()),
+    }
+}
+
+/// Sets up the same night `shuffles + 1` times with `night`, which should build a scenario and end with [`resolve_night`].
+/// Panics naming the players whose outcome depends on the order listeners of the same [`ListenerOrder`] happen to run in.
+///
+/// [`ListenerOrder`]: mafia_server::game::event::registry::ListenerOrder
+pub fn assert_night_order_independent(shuffles: u64, mut night: impl FnMut(NightOrder) -> NightOutcome) {
+    let expected = night(NightOrder::Subscribed);
+
+    for seed in 0..shuffles {
+        let actual = night(NightOrder::Shuffled(seed));
+        if actual == expected {continue}
+
+        let mut report = String::new();
+        for (expected, actual) in expected.players.iter().zip(actual.players.iter()) {
+            if expected != actual {
+                report += &format!("\n{} ({}):\n  subscribed order: {expected:?}\n  shuffled order:   {actual:?}", expected.player.index(), expected.role);
+            }
+        }
+        if expected.visits != actual.visits {
+            report += &format!("\nvisits:\n  subscribed order: {:?}\n  shuffled order:   {:?}", expected.visits, actual.visits);
+        }
+        panic!("Night outcome depends on listener order (shuffle seed {seed}):{report}");
+    }
+}
+
+fn sorted_debug<T: std::fmt::Debug>(items: &[T]) -> Vec<String> {
+    let mut items: Vec<String> = items.iter().map(|item| format!("{item:?}")).collect();
+    items.sort();
+    items
+}
@@ -0,0 +1,181 @@
+use std::{net::SocketAddr, path::PathBuf, time::{Duration, Instant}};
+
+use mafia_server::{
+    clock::{ClockSpeed, ManualClock},
+    packet::ToClientPacket,
+    room::RoomClientID,
+    websocket_connections::connection::Connection,
+    websocket_listener::{RoomCode, WebsocketListener}
+};
+use serde_json::Value;
+use tokio::{runtime::{Builder, Runtime}, sync::mpsc::{self, UnboundedReceiver}};
+use tokio_tungstenite::tungstenite::Message;
+
+/// A real [`WebsocketListener`] with in-memory connections instead of sockets.
+/// Nothing happens on its own: time only passes when [`TestServer::tick`] is called,
+/// and room codes and random names are seeded.
+pub struct TestServer {
+    listener: WebsocketListener,
+    clock: ManualClock,
+    next_port: u16,
+    /// Packets are handled inside this, like they are inside the real server's runtime, so they can spawn blocking work
+    runtime: Runtime,
+}
+
+/// One browser tab connected to a [`TestServer`]
+pub struct TestClient {
+    connection: Connection,
+    receiver: UnboundedReceiver<ToClientPacket>,
+    /// Received but not yet returned by [`TestClient::received`]
+    unread: Vec<ToClientPacket>,
+    /// Every packet this client has received
+    history: Vec<ToClientPacket>,
+}
+
+impl TestServer {
+    pub fn new() -> Self {
+        let clock = ManualClock::new();
+        Self {
+            listener: WebsocketListener::with_clock(Box::new(clock.clone())).with_seed(0),
+            clock,
+            next_port: 1,
+            runtime: Builder::new_current_thread().build().expect("A runtime for the test server"),
+        }
+    }
+
+    /// A server whose rooms pass `speed` times as much time as each tick
+    pub fn with_speed(speed: f64) -> Self {
+        let mut server = Self::new();
+        server.listener = server.listener.with_speed(ClockSpeed::new(speed).expect("A positive speed"));
+        server
+    }
+
+    /// A server that reads its ban list from `path`, which is checked for changes every tick
+    pub fn with_ban_list(path: PathBuf) -> Self {
+        let mut server = Self::new();
+        server.listener = server.listener.with_ban_list(path);
+        server
+    }
+
+    /// Each connection gets its own port, so two connections from the same test are different clients
+    pub fn connect(&mut self) -> TestClient {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let connection = Connection::new(sender, SocketAddr::from(([127, 0, 0, 1], self.next_port)));
+        self.next_port = self.next_port.checked_add(1).expect("Fewer than 65536 connections in one test");
+
+        self.listener.on_connect(&connection);
+        TestClient { connection, receiver, unread: Vec::new(), history: Vec::new() }
+    }
+
+    pub fn disconnect(&mut self, client: TestClient) {
+        self.listener.on_disconnect(client.connection);
+    }
+
+    /// Sends a packet the way the client writes it, so it goes through the same parsing as a real one
+    pub fn send(&mut self, client: &TestClient, packet: Value) {
+        let _runtime = self.runtime.enter();
+        self.listener.on_message(&client.connection, &Message::text(packet.to_string()));
+    }
+
+    /// Passes `time` in one tick, like the tick loop would over that many seconds
+    pub fn tick(&mut self, time: Duration) {
+        self.clock.advance(time);
+        self.listener.tick();
+    }
+
+    /// Hosts a new room with a new connection
+    pub fn host(&mut self) -> TestClient {
+        let client = self.connect();
+        self.send(&client, serde_json::json!({"type": "host"}));
+        client
+    }
+
+    /// Joins `room_code` with a new connection
+    pub fn join(&mut self, room_code: RoomCode) -> TestClient {
+        let client = self.connect();
+        self.send(&client, serde_json::json!({"type": "join", "roomCode": room_code}));
+        client
+    }
+
+    /// Rejoins as the player `client` was, with a new connection like a reloaded page
+    pub fn reconnect(&mut self, mut client: TestClient) -> TestClient {
+        let (room_code, player_id) = (client.room_code(), client.player_id());
+        self.disconnect(client);
+
+        let client = self.connect();
+        self.send(&client, serde_json::json!({"type": "reJoin", "roomCode": room_code, "playerId": player_id}));
+        client
+    }
+}
+
+impl TestClient {
+    /// Packets received since the last call
+    pub fn received(&mut self) -> Vec<ToClientPacket> {
+        self.receive();
+        std::mem::take(&mut self.unread)
+    }
+
+    fn receive(&mut self) {
+        while let Ok(packet) = self.receiver.try_recv() {
+            self.history.push(packet.clone());
+            self.unread.push(packet);
+        }
+    }
+
+    /// The `type` of each packet received since the last call, as the client sees it
+    pub fn received_types(&mut self) -> Vec<String> {
+        self.received()
+            .iter()
+            .map(|packet| match serde_json::to_value(packet) {
+                Ok(Value::Object(packet)) => packet.get("type").and_then(Value::as_str).unwrap_or_default().to_owned(),
+                _ => String::new(),
+            })
+            .collect()
+    }
+
+    /// Panics unless the packets received since the last call have exactly these types, in this order
+    #[track_caller]
+    pub fn assert_received(&mut self, expected: &[&str]) {
+        assert_eq!(self.received_types(), expected);
+    }
+
+    /// Waits for a packet that is sent from another thread rather than during a call to the server.
+    /// Packets received before it stay unread.
+    #[track_caller]
+    pub fn wait_for<T>(&mut self, mut find: impl FnMut(&ToClientPacket) -> Option<T>) -> T {
+        let deadline = Instant::now() + Duration::from_secs(30);
+        loop {
+            self.receive();
+            if let Some((index, found)) = self.unread.iter().enumerate().find_map(|(index, packet)| Some((index, find(packet)?))) {
+                self.unread.remove(index);
+                return found;
+            }
+            assert!(Instant::now() < deadline, "Timed out waiting for a packet");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    /// The room this client was last accepted into
+    #[track_caller]
+    pub fn room_code(&mut self) -> RoomCode {
+        self.last_accept_join().0
+    }
+
+    /// This client's id in the room it was last accepted into
+    #[track_caller]
+    pub fn player_id(&mut self) -> RoomClientID {
+        self.last_accept_join().1
+    }
+
+    #[track_caller]
+    fn last_accept_join(&mut self) -> (RoomCode, RoomClientID) {
+        self.receive();
+        self.history.iter()
+            .rev()
+            .find_map(|packet| match packet {
+                ToClientPacket::AcceptJoin { room_code, player_id, .. } => Some((*room_code, *player_id)),
+                _ => None,
+            })
+            .expect("The client should have been accepted into a room")
+    }
+}
@@ -0,0 +1,249 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+use mafia_server::{
+    game::{chat::ChatMessageVariant, settings::{PhaseTimeSettings, SpectatorSettings}},
+    lobby::Lobby,
+    packet::{RejectJoinReason, RoomListFilter, ToClientPacket, ToServerPacket},
+    room::{RoomClientID, RoomState},
+    websocket_connections::connection::{ClientSender, Connection}
+};
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+const ADDRESS: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+struct TestClient {
+    id: RoomClientID,
+    sender: ClientSender,
+    receiver: UnboundedReceiver<ToClientPacket>,
+}
+
+impl TestClient {
+    fn join(lobby: &mut Lobby, port: u16) -> Self {
+        let (tx, receiver) = mpsc::unbounded_channel();
+        let sender = Connection::new(tx, SocketAddr::from(([127, 0, 0, 1], port))).sender();
+
+        let Ok(result) = lobby.join_client(&sender) else { panic!("Failed to join lobby") };
+        lobby.initialize_client(result.id, &sender);
+
+        TestClient { id: result.id, sender, receiver }
+    }
+
+    fn send(&self, lobby: &mut Lobby, packet: ToServerPacket) {
+        let _ = lobby.on_client_message(&self.sender, self.id, packet);
+    }
+
+    fn received_packets(&mut self) -> Vec<ToClientPacket> {
+        let mut packets = Vec::new();
+        while let Ok(packet) = self.receiver.try_recv() {
+            packets.push(packet);
+        }
+        packets
+    }
+
+    fn received_lobby_messages(&mut self) -> Vec<String> {
+        self.received_packets()
+            .into_iter()
+            .filter_map(|packet| if let ToClientPacket::AddChatMessages { chat_messages } = packet {
+                Some(chat_messages)
+            } else {
+                None
+            })
+            .flat_map(|messages| messages.into_iter().map(|(_, message)| message.variant))
+            .filter_map(|variant| if let ChatMessageVariant::LobbyMessage { text, .. } = variant {
+                Some(text)
+            } else {
+                None
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn lobby_chat_history_is_sent_to_late_joiners() {
+    let mut lobby = Lobby::new();
+    let host = TestClient::join(&mut lobby, 1);
+
+    host.send(&mut lobby, ToServerPacket::SendLobbyMessage { text: "first".to_string() });
+    host.send(&mut lobby, ToServerPacket::SendLobbyMessage { text: "second".to_string() });
+
+    let mut late = TestClient::join(&mut lobby, 2);
+
+    assert_eq!(late.received_lobby_messages(), vec!["first".to_string(), "second".to_string()]);
+}
+
+#[test]
+fn lobby_chat_muted_client_cannot_send() {
+    let mut lobby = Lobby::new();
+    let mut host = TestClient::join(&mut lobby, 1);
+    let player = TestClient::join(&mut lobby, 2);
+
+    host.send(&mut lobby, ToServerPacket::HostSetClientMuted { id: player.id, muted: true });
+    host.received_packets();
+    player.send(&mut lobby, ToServerPacket::SendLobbyMessage { text: "hello".to_string() });
+    assert!(host.received_lobby_messages().is_empty());
+
+    // Only the host can mute
+    player.send(&mut lobby, ToServerPacket::HostSetClientMuted { id: host.id, muted: true });
+    host.send(&mut lobby, ToServerPacket::SendLobbyMessage { text: "still here".to_string() });
+    assert_eq!(host.received_lobby_messages(), vec!["still here".to_string()]);
+
+    host.send(&mut lobby, ToServerPacket::HostSetClientMuted { id: player.id, muted: false });
+    player.send(&mut lobby, ToServerPacket::SendLobbyMessage { text: "hello".to_string() });
+    assert_eq!(host.received_lobby_messages(), vec!["hello".to_string()]);
+}
+
+#[test]
+fn lobby_chat_word_filter() {
+    let mut lobby = Lobby::new();
+    let mut host = TestClient::join(&mut lobby, 1);
+
+    host.send(&mut lobby, ToServerPacket::SetLobbyWordFilter { words: vec!["Darn".to_string()] });
+    host.received_packets();
+    host.send(&mut lobby, ToServerPacket::SendLobbyMessage { text: "DARN it, darnit".to_string() });
+
+    assert_eq!(host.received_lobby_messages(), vec!["**** it, darnit".to_string()]);
+}
+
+#[test]
+fn lobby_chat_word_filter_only_matches_whole_words() {
+    let mut lobby = Lobby::new();
+    let mut host = TestClient::join(&mut lobby, 1);
+
+    host.send(&mut lobby, ToServerPacket::SetLobbyWordFilter { words: vec!["ass".to_string()] });
+    host.received_packets();
+    host.send(&mut lobby, ToServerPacket::SendLobbyMessage { text: "pass the class, ass.".to_string() });
+
+    assert_eq!(host.received_lobby_messages(), vec!["pass the class, ***.".to_string()]);
+}
+
+#[test]
+fn lobby_chat_deleted_message_is_not_replayed() {
+    let mut lobby = Lobby::new();
+    let mut host = TestClient::join(&mut lobby, 1);
+
+    host.send(&mut lobby, ToServerPacket::SendLobbyMessage { text: "keep".to_string() });
+    host.send(&mut lobby, ToServerPacket::SendLobbyMessage { text: "delete".to_string() });
+    host.send(&mut lobby, ToServerPacket::HostDeleteLobbyMessage { index: 1 });
+
+    assert!(host.received_packets().iter().any(|packet|
+        matches!(packet, ToClientPacket::LobbyMessageDeleted { index: 1 })
+    ));
+
+    let mut late = TestClient::join(&mut lobby, 2);
+    assert_eq!(late.received_lobby_messages(), vec!["keep".to_string()]);
+}
+
+#[test]
+fn phase_times_with_invalid_percentages_are_ignored() {
+    let mut lobby = Lobby::new();
+    let mut host = TestClient::join(&mut lobby, 1);
+    host.received_packets();
+
+    let phase_time_settings = PhaseTimeSettings { day_percentages: vec![100, 0], ..Default::default() };
+    host.send(&mut lobby, ToServerPacket::SetPhaseTimes { phase_time_settings });
+
+    assert!(host.received_packets().is_empty());
+    assert!(lobby.settings.phase_times.day_percentages.is_empty());
+}
+
+#[test]
+fn room_access_password_and_invite_codes() {
+    let mut lobby = Lobby::new();
+    let host = TestClient::join(&mut lobby, 1);
+    assert!(lobby.access.check_join(ADDRESS, None, None).is_ok());
+
+    host.send(&mut lobby, ToServerPacket::SetRoomPassword { password: Some("hunter2".to_string()) });
+    assert!(matches!(lobby.access.check_join(ADDRESS, None, None), Err(RejectJoinReason::WrongPassword)));
+    assert!(matches!(lobby.access.check_join(ADDRESS, Some("hunter3"), None), Err(RejectJoinReason::WrongPassword)));
+    assert!(matches!(lobby.access.check_join(ADDRESS, Some("hunter2"), None), Ok(None)));
+
+    let Some(code) = lobby.access.create_invite_code() else { panic!("Failed to create invite code") };
+    assert!(matches!(lobby.access.check_join(ADDRESS, None, Some(&code)), Ok(Some(_))));
+    lobby.access.use_invite_code(&code);
+    assert!(matches!(lobby.access.check_join(ADDRESS, None, Some(&code)), Err(RejectJoinReason::WrongPassword)));
+
+    host.send(&mut lobby, ToServerPacket::SetRoomPassword { password: Some("".to_string()) });
+    assert!(lobby.access.check_join(ADDRESS, None, None).is_ok());
+}
+
+#[test]
+fn room_access_long_password() {
+    let mut lobby = Lobby::new();
+    let host = TestClient::join(&mut lobby, 1);
+    let password = "correct horse battery staple\nand more";
+
+    host.send(&mut lobby, ToServerPacket::SetRoomPassword { password: Some(password.to_string()) });
+    assert!(matches!(lobby.access.check_join(ADDRESS, Some(password), None), Ok(None)));
+    assert!(matches!(lobby.access.check_join(ADDRESS, Some("correct horse"), None), Err(RejectJoinReason::WrongPassword)));
+}
+
+#[test]
+fn room_access_bans() {
+    let mut lobby = Lobby::new();
+    let _host = TestClient::join(&mut lobby, 1);
+    let player = TestClient::join(&mut lobby, 2);
+    let other_address = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+
+    lobby.access.ban(Some(ADDRESS), player.id);
+    let _ = lobby.remove_client(player.id);
+
+    assert!(matches!(lobby.access.check_join(ADDRESS, None, None), Err(RejectJoinReason::Banned)));
+    assert!(lobby.access.check_join(other_address, None, None).is_ok());
+    assert!(lobby.access.is_client_id_banned(player.id));
+
+    // A new client never receives the banned client's id
+    let new_player = TestClient::join(&mut lobby, 3);
+    assert_ne!(new_player.id, player.id);
+}
+
+#[test]
+fn room_preview_and_filters() {
+    let mut lobby = Lobby::new();
+    let host = TestClient::join(&mut lobby, 1);
+    let _player = TestClient::join(&mut lobby, 2);
+    host.send(&mut lobby, ToServerPacket::SetRoomPassword { password: Some("hunter2".to_string()) });
+
+    let preview = lobby.get_preview_data();
+    assert_eq!(preview.players.len(), 2);
+    assert_eq!(preview.role_list_size, 2);
+    assert!(preview.host_name.is_some());
+    assert!(preview.phase.is_none());
+    assert!(preview.password_protected);
+    assert!(preview.spectators_allowed);
+    assert!(preview.has_space(None));
+
+    assert!(RoomListFilter::default().matches(&preview));
+    assert!(RoomListFilter { not_in_game: true, has_space: true, max_players: Some(3) }.matches(&preview));
+    assert!(!RoomListFilter { has_space: true, max_players: Some(2), ..Default::default() }.matches(&preview));
+    assert!(!RoomListFilter { max_players: Some(1), ..Default::default() }.matches(&preview));
+}
+
+#[test]
+fn room_preview_counts_spectators() {
+    let mut lobby = Lobby::new();
+    let host = TestClient::join(&mut lobby, 1);
+    let spectator = TestClient::join(&mut lobby, 2);
+    spectator.send(&mut lobby, ToServerPacket::SetSpectator { spectator: true });
+    host.send(&mut lobby, ToServerPacket::SetSpectatorSettings {
+        spectator_settings: SpectatorSettings { allow_mid_game_join: false, ..Default::default() }
+    });
+
+    let preview = lobby.get_preview_data();
+    assert_eq!(preview.players.len(), 1);
+    assert_eq!(preview.spectators, 1);
+    assert!(!preview.spectators_allowed);
+    assert!(!RoomListFilter { max_players: Some(1), ..Default::default() }.matches(&preview));
+    assert!(RoomListFilter { max_players: Some(2), ..Default::default() }.matches(&preview));
+}
+
+#[test]
+fn games_only_have_space_when_spectators_can_join() {
+    let mut lobby = Lobby::new();
+    let _host = TestClient::join(&mut lobby, 1);
+    let mut preview = lobby.get_preview_data();
+    preview.in_game = true;
+
+    assert!(preview.has_space(None));
+    preview.spectators_allowed = false;
+    assert!(!preview.has_space(None));
+}
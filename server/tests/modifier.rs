@@ -0,0 +1,185 @@
+mod kit;
+
+use mafia_server::game::{
+    modifiers::{
+        day_start::DayStart, night_1_investigative_only::Night1InvestigativeOnly, night_breakdown::NightBreakdown,
+        no_night_1_attacks::NoNight1Attacks, ModifierState
+    },
+    components::night_trace::{NightTrace, NightTraceAttackResult, NightTraceEvent},
+    phase_schedule::{PhaseSchedule, ScheduledPhase}, settings::Settings, verdict::Verdict,
+    RejectStartReason, prelude::*
+};
+
+use crate::kit::{assert_contains, assert_not_contains};
+
+fn with_modifiers(modifiers: impl IntoIterator<Item = ModifierState>) -> Settings {
+    let mut settings = Settings::default();
+    for modifier in modifiers {
+        settings.modifiers.set_modifier(modifier);
+    }
+    settings
+}
+
+#[test]
+fn no_night_1_attacks() {
+    // A jester executed on day 1 haunts on night 1, and can't be stopped by protection
+    let settings = with_modifiers([
+        ModifierState::DayStart(DayStart),
+        ModifierState::NoNight1Attacks(NoNight1Attacks),
+        ModifierState::NightBreakdown(NightBreakdown)
+    ]);
+    kit::scenario!(game in Nomination 1 with settings where
+        jester: Jester,
+        townie: Villager,
+        detective: Detective,
+        mafioso: Mafioso
+    );
+
+    townie.vote_for_player(Some(jester));
+    detective.vote_for_player(Some(jester));
+    mafioso.vote_for_player(Some(jester));
+
+    game.skip_to(PhaseType::Judgement, 1);
+    townie.set_verdict(Verdict::Guilty);
+    detective.set_verdict(Verdict::Guilty);
+    mafioso.set_verdict(Verdict::Guilty);
+
+    game.skip_to(PhaseType::Night, 1);
+    assert!(!jester.alive());
+    jester.send_ability_input_player_list_typical(townie);
+
+    game.next_phase();
+    assert!(townie.alive());
+    // The attack never happened, so nobody survived it
+    assert_not_contains!(townie.get_messages_after_night(1), ChatMessageVariant::YouSurvivedAttack);
+    assert_not_contains!(jester.get_messages_after_night(1), ChatMessageVariant::SomeoneSurvivedYourAttack);
+    let night = NightTrace::nights(&game).first().expect("night 1 is traced");
+    assert!(night.steps.iter().flat_map(|step| step.events.iter()).any(|event| matches!(
+        event,
+        NightTraceEvent::Attack { defender, result: NightTraceAttackResult::Prevented, .. } if *defender == townie.player_ref()
+    )));
+
+    game.skip_to(PhaseType::Night, 2);
+    mafioso.send_ability_input_player_list_typical(townie);
+    game.next_phase();
+    assert!(!townie.alive());
+}
+
+#[test]
+fn night_1_investigative_only() {
+    let settings = with_modifiers([ModifierState::Night1InvestigativeOnly(Night1InvestigativeOnly)]);
+    kit::scenario!(game in Night 1 with settings where
+        godfather: Godfather,
+        goon: Goon,
+        townie: Villager,
+        doctor: Doctor,
+        detective: Detective
+    );
+
+    godfather.send_ability_input_player_list_typical(townie);
+    doctor.send_ability_input_player_list_typical(townie);
+    detective.send_ability_input_player_list_typical(goon);
+    game.next_phase();
+
+    assert!(townie.alive());
+    assert_contains!(godfather.get_messages_after_night(1), ChatMessageVariant::RoleBlocked);
+    assert_contains!(goon.get_messages_after_night(1), ChatMessageVariant::RoleBlocked);
+    assert_contains!(doctor.get_messages_after_night(1), ChatMessageVariant::RoleBlocked);
+    assert_not_contains!(detective.get_messages_after_night(1), ChatMessageVariant::RoleBlocked);
+    assert_contains!(
+        detective.get_messages_after_night(1),
+        ChatMessageVariant::DetectiveResult { suspicious: true }
+    );
+}
+
+#[test]
+fn day_start() {
+    let settings = with_modifiers([ModifierState::DayStart(DayStart)]);
+    kit::scenario!(game in Briefing 1 with settings where
+        _mafia: Mafioso,
+        _townie: Villager,
+        _detective: Detective
+    );
+
+    game.next_phase();
+    assert_eq!(game.current_phase().phase(), PhaseType::Discussion);
+    assert_eq!(game.day_number(), 1);
+
+    game.next_phase();
+    assert_eq!(game.current_phase().phase(), PhaseType::Nomination);
+
+    // The lobby gets the host's schedule back
+    assert_eq!(game.settings.phase_schedule.first_day, PhaseSchedule::default().first_day);
+}
+
+#[test]
+fn day_start_is_rejected_when_the_first_day_is_too_long() {
+    let mut settings = with_modifiers([ModifierState::DayStart(DayStart)]);
+    settings.phase_schedule.first_day = [ScheduledPhase::Dusk, ScheduledPhase::Night].into_iter()
+        .chain([ScheduledPhase::Obituary, ScheduledPhase::Discussion, ScheduledPhase::Dusk, ScheduledPhase::Night].repeat(3))
+        .collect();
+    assert!(settings.phase_schedule.is_valid());
+
+    assert!(matches!(
+        mafia_server::game::test::mock_game(settings, 3),
+        Err(RejectStartReason::InvalidPhaseSchedule)
+    ));
+}
+
+#[test]
+fn night_breakdown_traces_attacks_and_blocks() {
+    kit::scenario!(game in Night 2 where
+        mafia: Mafioso,
+        townie: Villager,
+        doctor: Doctor,
+        escort: Escort,
+        _detective: Detective
+    );
+    game.settings.modifiers.set_modifier(ModifierState::NightBreakdown(NightBreakdown));
+
+    mafia.send_ability_input_player_list_typical(townie);
+    doctor.send_ability_input_player_list_typical(townie);
+    escort.send_ability_input_player_list_typical(_detective);
+    game.next_phase();
+    assert!(townie.alive());
+
+    let nights = NightTrace::nights(&game);
+    assert_eq!(nights.len(), 1);
+    let night = nights.first().expect("night 2 is traced");
+    assert_eq!(night.night, 2);
+
+    let events: Vec<(OnMidnightPriority, &NightTraceEvent)> = night.steps.iter()
+        .flat_map(|step| step.events.iter().map(move |event| (step.priority, event)))
+        .collect();
+
+    assert!(events.iter().any(|(priority, event)|
+        *priority == OnMidnightPriority::InitializeNight &&
+        matches!(event, NightTraceEvent::VisitAdded { visit } if visit.visitor == mafia.player_ref() && visit.target == townie.player_ref())
+    ));
+    assert!(events.iter().any(|(priority, event)|
+        *priority == OnMidnightPriority::Roleblock &&
+        matches!(event, NightTraceEvent::Roleblocked { player, invisible: false } if *player == _detective.player_ref())
+    ));
+    assert!(events.iter().any(|(priority, event)|
+        *priority == OnMidnightPriority::Kill &&
+        matches!(event, NightTraceEvent::Attack { defender, result: NightTraceAttackResult::Defended, .. } if *defender == townie.player_ref())
+    ));
+    assert!(events.iter().any(|(_, event)| matches!(event, NightTraceEvent::AbilityActed { .. })));
+
+    let json = serde_json::to_string(nights).expect("trace serializes");
+    assert!(json.contains("\"priority\":\"kill\""));
+}
+
+#[test]
+fn night_breakdown_is_opt_in() {
+    kit::scenario!(game in Night 2 where
+        mafia: Mafioso,
+        townie: Villager,
+        _detective: Detective
+    );
+
+    mafia.send_ability_input_player_list_typical(townie);
+    game.next_phase();
+    assert!(!townie.alive());
+    assert!(NightTrace::nights(&game).is_empty());
+}
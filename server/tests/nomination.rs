@@ -0,0 +1,237 @@
+mod kit;
+
+use std::time::Duration;
+
+use mafia_server::{
+    game::{
+        components::{
+            nomination_revote::NominationRevote,
+            vote_history::{VoteHistory, VoteHistoryVote}
+        },
+        controllers::{ControllerID, ControllerInput, PlayerListSelection},
+        modifiers::{
+            approval_nominations::ApprovalNominations,
+            plurality_nominations::{PluralityNominations, PluralityTieBreak},
+            vote_lock::VoteLock,
+            ModifierState
+        },
+        phase::{PhaseState, PhaseStateMachine, PhaseType},
+        prelude::*,
+        verdict::Verdict
+    },
+    room::RoomState,
+    vec_set
+};
+
+use crate::kit::player::TestPlayer;
+
+fn plurality(tie_break: PluralityTieBreak) -> ModifierState {
+    ModifierState::PluralityNominations(PluralityNominations { tie_break })
+}
+
+fn approve(voter: TestPlayer, targets: &[TestPlayer]) {
+    voter.send_ability_input(ControllerInput::new(
+        ControllerID::nominate(voter.player_ref()),
+        PlayerListSelection(targets.iter().map(|p| p.player_ref()).collect())
+    ));
+}
+
+fn player_on_trial(phase: &PhaseState) -> Option<PlayerReference> {
+    if let PhaseState::Testimony { player_on_trial, .. } = phase {
+        Some(*player_on_trial)
+    } else {
+        None
+    }
+}
+
+#[test]
+fn plurality_without_majority() {
+    kit::scenario!(game in Nomination 2 where
+        detective: Detective,
+        townie: Villager,
+        _doctor: Doctor,
+        _lookout: Lookout,
+        _goon: Goon
+    );
+    game.settings.modifiers.set_modifier(plurality(PluralityTieBreak::NoTrial));
+
+    detective.vote_for_player(townie);
+    game.next_phase();
+
+    assert_eq!(player_on_trial(game.current_phase()), Some(townie.player_ref()));
+}
+
+#[test]
+fn plurality_tie_puts_nobody_on_trial() {
+    kit::scenario!(game in Nomination 2 where
+        detective: Detective,
+        townie: Villager,
+        doctor: Doctor,
+        lookout: Lookout,
+        _goon: Goon
+    );
+    game.settings.modifiers.set_modifier(plurality(PluralityTieBreak::NoTrial));
+
+    detective.vote_for_player(townie);
+    doctor.vote_for_player(lookout);
+    game.next_phase();
+
+    assert_eq!(game.current_phase().phase(), PhaseType::Adjournment);
+}
+
+#[test]
+fn plurality_random_tie_break() {
+    kit::scenario!(game in Nomination 2 where
+        detective: Detective,
+        townie: Villager,
+        doctor: Doctor,
+        lookout: Lookout,
+        _goon: Goon
+    );
+    game.settings.modifiers.set_modifier(plurality(PluralityTieBreak::Random));
+
+    detective.vote_for_player(townie);
+    doctor.vote_for_player(lookout);
+    game.next_phase();
+
+    let Some(player_on_trial) = player_on_trial(game.current_phase()) else { panic!("Nobody was put on trial") };
+    assert!(player_on_trial == townie.player_ref() || player_on_trial == lookout.player_ref());
+}
+
+#[test]
+fn plurality_revote_between_tied_players() {
+    kit::scenario!(game in Nomination 2 where
+        detective: Detective,
+        townie: Villager,
+        doctor: Doctor,
+        lookout: Lookout,
+        goon: Goon
+    );
+    game.settings.modifiers.set_modifier(plurality(PluralityTieBreak::Revote));
+
+    detective.vote_for_player(townie);
+    doctor.vote_for_player(lookout);
+    game.next_phase();
+
+    assert!(matches!(game.current_phase(), PhaseState::Nomination { trials_left: 3 }));
+    assert_eq!(NominationRevote::candidates(&game), Some(&vec_set![townie.player_ref(), lookout.player_ref()]));
+
+    // Only the tied players can be nominated
+    goon.vote_for_player(detective);
+    doctor.vote_for_player(lookout);
+    game.next_phase();
+
+    assert_eq!(player_on_trial(game.current_phase()), Some(lookout.player_ref()));
+    assert!(NominationRevote::candidates(&game).is_none());
+}
+
+#[test]
+fn forcing_the_next_phase_cancels_a_revote() {
+    kit::scenario!(game in Nomination 2 where
+        detective: Detective,
+        townie: Villager,
+        doctor: Doctor,
+        lookout: Lookout,
+        _goon: Goon
+    );
+    game.settings.modifiers.set_modifier(plurality(PluralityTieBreak::Revote));
+
+    detective.vote_for_player(townie);
+    doctor.vote_for_player(lookout);
+    PhaseStateMachine::next_phase(&mut game, Some(PhaseState::Dusk));
+
+    assert_eq!(game.current_phase().phase(), PhaseType::Dusk);
+    assert!(NominationRevote::candidates(&game).is_none());
+}
+
+#[test]
+fn approval_nominations_count_every_pick() {
+    kit::scenario!(game in Nomination 2 where
+        detective: Detective,
+        townie: Villager,
+        doctor: Doctor,
+        lookout: Lookout,
+        goon: Goon
+    );
+    game.settings.modifiers.set_modifier(ModifierState::ApprovalNominations(ApprovalNominations));
+
+    approve(detective, &[goon, townie]);
+    approve(doctor, &[goon]);
+    approve(lookout, &[goon, doctor]);
+    game.next_phase();
+
+    assert_eq!(player_on_trial(game.current_phase()), Some(goon.player_ref()));
+}
+
+#[test]
+fn vote_history_records_every_change() {
+    kit::scenario!(game in Nomination 2 where
+        detective: Detective,
+        townie: Villager,
+        doctor: Doctor,
+        _lookout: Lookout,
+        goon: Goon
+    );
+
+    detective.vote_for_player(goon);
+    detective.vote_for_player(townie);
+    detective.vote_for_player(None);
+
+    let votes: Vec<VoteHistoryVote> = VoteHistory::entries_on_day(&game, 2).map(|entry| entry.vote.clone()).collect();
+    assert_eq!(votes, vec![
+        VoteHistoryVote::Nomination { votees: vec_set![goon.player_ref()] },
+        VoteHistoryVote::Nomination { votees: vec_set![townie.player_ref()] },
+        VoteHistoryVote::Nomination { votees: vec_set![] },
+    ]);
+
+    detective.vote_for_player(goon);
+    doctor.vote_for_player(goon);
+    townie.vote_for_player(goon);
+    game.skip_to(PhaseType::Judgement, 2);
+
+    detective.set_verdict(Verdict::Guilty);
+    detective.set_verdict(Verdict::Innocent);
+
+    let Some(last) = VoteHistory::entries(&game).last() else { panic!("No votes were recorded") };
+    assert_eq!(last.voter, detective.player_ref());
+    assert_eq!(last.phase, PhaseType::Judgement);
+    assert_eq!(last.vote, VoteHistoryVote::Verdict { verdict: Verdict::Innocent });
+    assert_eq!(VoteHistory::entries_on_day(&game, 2).count(), 8);
+}
+
+#[test]
+fn vote_history_records_when_in_the_phase_votes_happened() {
+    kit::scenario!(game in Nomination 2 where
+        detective: Detective,
+        _townie: Villager,
+        goon: Goon
+    );
+
+    detective.vote_for_player(goon);
+    let _ = game.tick(Duration::from_secs(5));
+    detective.vote_for_player(None);
+
+    let seconds: Vec<u16> = VoteHistory::entries(&game).iter().map(|entry| entry.seconds_into_phase).collect();
+    assert_eq!(seconds, vec![0, 5]);
+}
+
+#[test]
+fn vote_lock_in_last_seconds() {
+    kit::scenario!(game in Discussion 2 where
+        detective: Detective,
+        townie: Villager,
+        _doctor: Doctor,
+        _lookout: Lookout,
+        goon: Goon
+    );
+    game.settings.modifiers.set_modifier(ModifierState::VoteLock(VoteLock { seconds: 10 }));
+    game.settings.phase_times.nomination = 30;
+    game.next_phase();
+
+    detective.vote_for_player(goon);
+    let _ = game.tick(Duration::from_secs(25));
+
+    detective.vote_for_player(townie);
+    assert_eq!(detective.player_ref().chosen_vote(&game), Some(goon.player_ref()));
+    assert_eq!(VoteHistory::entries(&game).len(), 1);
+}
@@ -0,0 +1,188 @@
+mod kit;
+
+use std::time::Duration;
+
+use mafia_server::{
+    game::{
+        phase::{PhaseState, PhaseType},
+        phase_schedule::{PhaseSchedule, ScheduledPhase::{self, *}},
+        prelude::*,
+        settings::{PhaseTimeSettings, Settings, TrialSettings},
+        test::mock_game,
+        RejectStartReason
+    },
+    vec_map
+};
+
+#[test]
+fn trials_decay_per_day() {
+    let settings = TrialSettings { trials_per_day: 3, decay_per_day: 1, minimum_trials: 2, adjournment: true };
+
+    assert_eq!(settings.trials_on_day(2), 3);
+    assert_eq!(settings.trials_on_day(3), 2);
+    assert_eq!(settings.trials_on_day(10), 2);
+}
+
+#[test]
+fn configured_trials_per_day() {
+    kit::scenario!(game in Discussion 2 where
+        _detective: Detective,
+        _townie: Villager,
+        _goon: Goon
+    );
+    game.settings.trials.trials_per_day = 1;
+
+    game.next_phase();
+    assert!(matches!(game.current_phase(), PhaseState::Nomination { trials_left: 1 }));
+
+    game.next_phase();
+    assert!(matches!(game.current_phase(), PhaseState::Adjournment { trials_left: 0 }));
+
+    game.next_phase();
+    assert_eq!(game.current_phase().phase(), PhaseType::Dusk);
+}
+
+#[test]
+fn adjournment_can_be_disabled() {
+    kit::scenario!(game in Nomination 2 where
+        _detective: Detective,
+        _townie: Villager,
+        _goon: Goon
+    );
+    game.settings.trials.adjournment = false;
+
+    game.next_phase();
+    assert!(matches!(game.current_phase(), PhaseState::Nomination { trials_left: 2 }));
+}
+
+#[test]
+fn endgame_and_day_time_scaling() {
+    let mut settings = PhaseTimeSettings::default();
+    let discussion = Duration::from_secs(settings.discussion.into());
+    let night = Duration::from_secs(settings.night.into());
+
+    // By default phases are halved once three or fewer players are alive
+    assert_eq!(settings.get_scaled_time_for(PhaseType::Discussion, 2, 4), Some(discussion));
+    assert_eq!(settings.get_scaled_time_for(PhaseType::Discussion, 2, 3), Some(discussion / 2));
+
+    settings.endgame.phase_percentages = vec_map![(PhaseType::Discussion, 200)];
+    assert_eq!(settings.get_scaled_time_for(PhaseType::Discussion, 2, 3), Some(discussion * 2));
+    assert_eq!(settings.get_scaled_time_for(PhaseType::Night, 2, 3), Some(night / 2));
+
+    settings.day_percentages = vec![100, 150, 100];
+    assert_eq!(settings.get_scaled_time_for(PhaseType::Discussion, 2, 10), Some(discussion * 3 / 2));
+    assert_eq!(settings.get_scaled_time_for(PhaseType::Discussion, 5, 10), Some(discussion));
+}
+
+#[test]
+fn scaled_phase_times_stay_in_bounds() {
+    let mut settings = PhaseTimeSettings { night: u16::MAX, ..Default::default() };
+    let discussion = Duration::from_secs(settings.discussion.into());
+
+    settings.day_percentages = vec![0];
+    assert_eq!(settings.get_scaled_time_for(PhaseType::Discussion, 1, 10), Some(discussion / 100));
+
+    settings.day_percentages = vec![u16::MAX];
+    settings.endgame.percentage = u16::MAX;
+    assert_eq!(settings.get_scaled_time_for(PhaseType::Night, 1, 0), Some(PhaseTimeSettings::MAX_SCALED_TIME));
+}
+
+#[test]
+fn phase_time_percentages_are_validated() {
+    assert!(PhaseTimeSettings::default().percentages_are_valid());
+    assert!(!PhaseTimeSettings { day_percentages: vec![100, 0], ..Default::default() }.percentages_are_valid());
+    assert!(!PhaseTimeSettings { day_percentages: vec![100; 300], ..Default::default() }.percentages_are_valid());
+
+    let mut settings = PhaseTimeSettings::default();
+    settings.endgame.percentage = 0;
+    assert!(!settings.percentages_are_valid());
+
+    let mut settings = PhaseTimeSettings::default();
+    settings.endgame.phase_percentages = vec_map![(PhaseType::Night, PhaseTimeSettings::MAX_PERCENTAGE + 1)];
+    assert!(!settings.percentages_are_valid());
+
+    assert!(matches!(
+        mock_game(Settings { phase_times: settings, ..Default::default() }, 3),
+        Err(RejectStartReason::InvalidPhaseTimes)
+    ));
+}
+
+#[test]
+fn games_with_no_time_on_some_day_are_rejected() {
+    let no_time = PhaseTimeSettings { obituary: 0, discussion: 0, nomination: 0, night: 0, dusk: 0, ..Default::default() };
+    assert!(no_time.game_ends_instantly());
+    assert!(!PhaseTimeSettings::default().game_ends_instantly());
+
+    assert!(matches!(
+        mock_game(Settings { phase_times: no_time, ..Default::default() }, 3),
+        Err(RejectStartReason::ZeroTimeGame)
+    ));
+}
+
+fn schedule(first_day: Vec<ScheduledPhase>, cycle: Vec<ScheduledPhase>) -> PhaseSchedule {
+    PhaseSchedule { first_day, cycle }
+}
+
+#[test]
+fn phase_schedule_validation() {
+    assert!(PhaseSchedule::default().is_valid());
+    assert!(schedule(vec![Discussion, Trials, Dusk, Night], vec![Obituary, Discussion, Trials, Dusk, Night]).is_valid());
+    assert!(schedule(vec![Night], vec![Obituary, Discussion, Trials, Night]).is_valid());
+
+    // Night must be followed by obituary, and obituary must follow night
+    assert!(!schedule(vec![Dusk, Night], vec![Discussion, Trials, Dusk, Night]).is_valid());
+    assert!(!schedule(vec![Dusk], vec![Obituary, Discussion, Trials, Dusk, Night]).is_valid());
+    assert!(!schedule(vec![], vec![Obituary, Discussion, Night]).is_valid());
+    // The game must keep reaching night
+    assert!(!schedule(vec![], vec![Discussion, Trials, Dusk]).is_valid());
+    assert!(!schedule(vec![], vec![]).is_valid());
+    assert!(!schedule(vec![Dusk, Dusk, Night], vec![Obituary, Discussion, Night]).is_valid());
+}
+
+#[test]
+fn day_start_schedule() {
+    let settings = Settings {
+        phase_schedule: schedule(vec![Discussion, Trials, Dusk, Night], PhaseSchedule::default().cycle),
+        ..Default::default()
+    };
+    kit::scenario!(game in Briefing 1 with settings where
+        _detective: Detective,
+        _townie: Villager,
+        _goon: Goon
+    );
+
+    game.next_phase();
+    assert_eq!(game.current_phase().phase(), PhaseType::Discussion);
+    assert_eq!(game.day_number(), 1);
+
+    game.next_phase();
+    assert_eq!(game.current_phase().phase(), PhaseType::Nomination);
+}
+
+#[test]
+fn double_night_schedule_without_dusk() {
+    let settings = Settings {
+        phase_schedule: schedule(vec![Night], vec![Obituary, Night, Obituary, Discussion, Trials, Night]),
+        ..Default::default()
+    };
+    kit::scenario!(game in Briefing 1 with settings where
+        _detective: Detective,
+        _townie: Villager,
+        _goon: Goon
+    );
+
+    let mut phases = Vec::new();
+    for _ in 0..6 {
+        game.next_phase();
+        phases.push((game.current_phase().phase(), game.day_number()));
+    }
+
+    assert_eq!(phases, vec![
+        (PhaseType::Night, 1),
+        (PhaseType::Obituary, 2),
+        (PhaseType::Night, 2),
+        (PhaseType::Obituary, 3),
+        (PhaseType::Discussion, 3),
+        (PhaseType::Nomination, 3),
+    ]);
+}
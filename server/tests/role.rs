@@ -8,7 +8,8 @@ pub use mafia_server::{
     room::RoomState,
     game::{
         abilities::syndicate_gun::SyndicateGun,
-        settings::{PhaseTimeSettings, Settings},
+        phase_schedule::PhaseSchedule,
+        settings::{InactivitySettings, PhaseTimeSettings, Settings, SpectatorSettings, TrialSettings},
         test::mock_game,
         verdict::Verdict,
         phase::{
@@ -3038,6 +3039,10 @@ fn recruiter_role_list_is_correct() {
             phase_times: PhaseTimeSettings::default(),
             enabled_roles: RoleSet::Any.get_roles(),
             modifiers: ModifierSettings::default(),
+            spectators: SpectatorSettings::default(),
+            trials: TrialSettings::default(),
+            inactivity: InactivitySettings::default(),
+            phase_schedule: PhaseSchedule::default(),
         },
         4
     ).unwrap();
@@ -3078,6 +3083,10 @@ fn juggernaut_wardblock() {
             phase_times: PhaseTimeSettings::default(),
             enabled_roles: vec_set![Role::Juggernaut, Role::Bouncer, Role::Villager, Role::Blackmailer, Role::Mortician],
             modifiers: ModifierSettings::default(),
+            spectators: SpectatorSettings::default(),
+            trials: TrialSettings::default(),
+            inactivity: InactivitySettings::default(),
+            phase_schedule: PhaseSchedule::default(),
         },
         3
     ).unwrap();
@@ -3097,4 +3106,34 @@ fn juggernaut_wardblock() {
 
     assert!(!Silenced::silenced(&game, villager.player_ref()));
     assert!(Tags::has_tag(&game, TagSetID::MorticianTag(juggernaut.player_ref()), bouncer.player_ref()))
-}
\ No newline at end of file
+}
+
+#[test]
+fn maverick_loses_its_other_roles_when_it_becomes_mafioso() {
+    kit::scenario!(game in Nomination 2 where
+        maverick: Maverick,
+        mafioso: Mafioso,
+        townie1: Villager,
+        townie2: Villager,
+        townie3: Villager
+    );
+    let role_abilities = |game: &Game| AbilityID::current_used_ids(game)
+        .iter()
+        .filter(|id| id.get_player_from_role_id() == Some(maverick.player_ref()))
+        .filter_map(|id| id.get_role_from_role_id())
+        .collect::<Vec<_>>();
+    assert_eq!(role_abilities(&game).len(), 3);
+
+    for player in [maverick, townie1, townie2, townie3] {
+        player.vote_for_player(Some(mafioso));
+    }
+    game.skip_to(Judgement, 2);
+    for player in [maverick, townie1, townie2, townie3] {
+        player.set_verdict(Verdict::Guilty);
+    }
+    game.skip_to(Night, 2);
+
+    assert!(!mafioso.alive());
+    assert_eq!(maverick.role(), Role::Mafioso);
+    assert_eq!(role_abilities(&game), vec![Role::Mafioso]);
+}
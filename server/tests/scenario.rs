@@ -0,0 +1,261 @@
+//! Runs every scenario file in `tests/scenarios`.
+//! See `tests/scenarios/README.md` for the format.
+mod kit;
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use kit::{game::TestGame, player::TestPlayer};
+use mafia_server::game::{
+    components::graves::{
+        grave::{GraveDeathCause, GraveInformation, GravePhase},
+        grave_reference::GraveReference
+    },
+    controllers::*,
+    game_conclusion::GameConclusion,
+    phase::PhaseType,
+    role::Role,
+    settings::Settings
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct Scenario {
+    #[serde(default)]
+    #[expect(dead_code, reason = "Only for whoever reads the file")]
+    description: String,
+    /// Player `i` is the `i`th player in this list
+    players: Vec<ScenarioPlayer>,
+    #[serde(default)]
+    nights: Vec<ScenarioNight>,
+    /// Every grave at the end of the scenario, in the order they were dug
+    graves: Option<Vec<ExpectedGrave>>,
+    /// `null` if the game shouldn't be over, left out if it doesn't matter
+    #[serde(default, deserialize_with = "deserialize_some")]
+    conclusion: Option<Option<GameConclusion>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct ScenarioPlayer {
+    name: String,
+    role: Role,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct ScenarioNight {
+    night: u8,
+    #[serde(default)]
+    actions: Vec<ScenarioAction>,
+    /// Checked the morning after
+    #[serde(default)]
+    expect: NightExpectations,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct ScenarioAction {
+    player: String,
+    /// Which of the role's abilities, most roles only have `0`
+    #[serde(default)]
+    controller: RoleControllerID,
+    select: ScenarioSelection,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum ScenarioSelection {
+    Unit,
+    Boolean(bool),
+    Integer(i8),
+    Players(Vec<String>),
+    TwoPlayers([String; 2]),
+    Roles(Vec<Role>),
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct NightExpectations {
+    #[serde(default)]
+    alive: Vec<String>,
+    #[serde(default)]
+    dead: Vec<String>,
+    /// Messages each player should have received since the night began, as the client receives them
+    #[serde(default)]
+    messages: BTreeMap<String, Vec<Value>>,
+    #[serde(default)]
+    not_messages: BTreeMap<String, Vec<Value>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct ExpectedGrave {
+    player: String,
+    died_phase: Option<GravePhase>,
+    day_number: Option<u8>,
+    /// `null` if the role should be obscured
+    #[serde(default, deserialize_with = "deserialize_some")]
+    role: Option<Option<Role>>,
+    death_causes: Option<Vec<GraveDeathCause>>,
+}
+
+fn deserialize_some<'de, T: Deserialize<'de>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+    T::deserialize(deserializer).map(Some)
+}
+
+#[test]
+fn scenarios() {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/scenarios");
+    let mut paths: Vec<_> = fs::read_dir(&directory)
+        .expect("tests/scenarios exists")
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "json"))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "No scenarios in {}", directory.display());
+
+    let failures: Vec<String> = paths.iter()
+        .filter_map(|path| {
+            let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+            let scenario = fs::read_to_string(path)
+                .map_err(|err| err.to_string())
+                .and_then(|file| serde_json::from_str::<Scenario>(&file).map_err(|err| format!("Invalid scenario: {err}")));
+
+            match scenario.and_then(|scenario| run(&scenario)) {
+                Ok(()) => None,
+                Err(err) => Some(format!("{name}: {err}")),
+            }
+        })
+        .collect();
+
+    assert!(failures.is_empty(), "{} of {} scenarios failed:\n{}", failures.len(), paths.len(), failures.join("\n"));
+}
+
+fn run(scenario: &Scenario) -> Result<(), String> {
+    let roles = scenario.players.iter().map(|player| player.role).collect();
+    let mut test_scenario = kit::_init::create_scenario(roles, Settings { random_seed: Some(0), ..Default::default() });
+    let mut game = TestGame::new(&mut test_scenario.game);
+
+    let players: BTreeMap<&str, TestPlayer> = scenario.players.iter()
+        .zip(test_scenario.players.iter())
+        .map(|(player, player_ref)| (player.name.as_str(), TestPlayer::new(*player_ref, &game)))
+        .collect();
+    if players.len() != scenario.players.len() {
+        return Err("Two players have the same name".to_owned());
+    }
+    let player = |name: &str| players.get(name).copied().ok_or_else(|| format!("There is no player named {name}"));
+
+    let mut last_night = 0;
+    for night in scenario.nights.iter() {
+        if night.night <= last_night {
+            return Err(format!("Night {} comes after night {last_night}", night.night));
+        }
+        last_night = night.night;
+        game.skip_to(PhaseType::Night, night.night);
+
+        for action in night.actions.iter() {
+            let actor = player(&action.player)?;
+            let selection: ControllerSelection = match &action.select {
+                ScenarioSelection::Unit => UnitSelection.into(),
+                ScenarioSelection::Boolean(boolean) => BooleanSelection(*boolean).into(),
+                ScenarioSelection::Integer(integer) => IntegerSelection(*integer).into(),
+                ScenarioSelection::Players(names) => PlayerListSelection(
+                    names.iter().map(|name| player(name).map(|p| p.player_ref())).collect::<Result<_, _>>()?
+                ).into(),
+                ScenarioSelection::TwoPlayers([a, b]) => TwoPlayerOptionSelection(
+                    Some((player(a)?.player_ref(), player(b)?.player_ref()))
+                ).into(),
+                ScenarioSelection::Roles(roles) => RoleListSelection(roles.clone()).into(),
+            };
+            actor.send_ability_input(ControllerInput::new(
+                ControllerID::role(actor.player_ref(), actor.role(), action.controller),
+                selection
+            ));
+        }
+
+        game.skip_to(PhaseType::Obituary, night.night.saturating_add(1));
+        check_night(night, &player).map_err(|err| format!("After night {}: {err}", night.night))?;
+    }
+
+    if let Some(graves) = &scenario.graves {
+        check_graves(&game, graves, &player)?;
+    }
+
+    if let Some(conclusion) = scenario.conclusion {
+        let actual = GameConclusion::game_is_over_game(&game);
+        if actual != conclusion {
+            return Err(format!("Expected the game to end with {conclusion:?}, but it ended with {actual:?}"));
+        }
+    }
+
+    Ok(())
+}
+
+fn check_night(night: &ScenarioNight, player: &impl Fn(&str) -> Result<TestPlayer, String>) -> Result<(), String> {
+    for name in night.expect.alive.iter() {
+        if !player(name)?.alive() {
+            return Err(format!("{name} should be alive"));
+        }
+    }
+    for name in night.expect.dead.iter() {
+        if player(name)?.alive() {
+            return Err(format!("{name} should be dead"));
+        }
+    }
+
+    for (name, expected, should_receive) in night.expect.messages.iter().map(|(name, messages)| (name, messages, true))
+        .chain(night.expect.not_messages.iter().map(|(name, messages)| (name, messages, false)))
+    {
+        let received: Vec<Value> = player(name)?
+            .get_messages_after_night(night.night)
+            .iter()
+            .filter_map(|message| serde_json::to_value(message).ok())
+            .collect();
+
+        for message in expected {
+            if received.contains(message) != should_receive {
+                return Err(format!(
+                    "{name} should {}have received {message}, but received {}",
+                    if should_receive {""} else {"not "},
+                    serde_json::to_string_pretty(&received).unwrap_or_default()
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_graves(game: &TestGame, expected: &[ExpectedGrave], player: &impl Fn(&str) -> Result<TestPlayer, String>) -> Result<(), String> {
+    let graves: Vec<_> = GraveReference::all_graves(game).map(|grave| grave.deref(game).clone()).collect();
+    if graves.len() != expected.len() {
+        return Err(format!("Expected {} graves, but there are {}: {graves:?}", expected.len(), graves.len()));
+    }
+
+    for (grave, expected) in graves.iter().zip(expected) {
+        let mismatch = || format!("Expected a grave for {}, but found {grave:?}", expected.player);
+
+        if grave.player != player(&expected.player)?.player_ref() {
+            return Err(mismatch());
+        }
+        if expected.died_phase.as_ref().is_some_and(|phase| *phase != grave.died_phase) {
+            return Err(mismatch());
+        }
+        if expected.day_number.is_some_and(|day| day != grave.day_number) {
+            return Err(mismatch());
+        }
+        if expected.role.is_some_and(|role| role != grave.role()) {
+            return Err(mismatch());
+        }
+        if let Some(death_causes) = &expected.death_causes {
+            let GraveInformation::Normal { death_causes: actual, .. } = &grave.information else {
+                return Err(mismatch());
+            };
+            if actual != death_causes {
+                return Err(mismatch());
+            }
+        }
+    }
+    Ok(())
+}
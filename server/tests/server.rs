@@ -0,0 +1,499 @@
+#[path = "kit/server.rs"]
+mod server;
+
+use std::time::Duration;
+
+use server::{TestClient, TestServer};
+use mafia_server::{
+    game::{
+        chat::ChatMessage, game_conclusion::GameConclusion, player::PlayerIndex, role::Role,
+        role_list_generation::probabilities::RoleListProbabilities
+    },
+    packet::{RejectJoinReason, ToClientPacket}
+};
+use serde_json::json;
+
+/// Everything `Lobby::send_settings` sends
+const LOBBY_SETTINGS: &[&str] = &[
+    "lobbyName", "phaseTimes", "randomSeed", "roleList", "enabledRoles", "modifierSettings",
+    "spectatorSettings", "trialSettings", "inactivitySettings", "phaseSchedule", "lobbyWordFilter", "roomAccess",
+];
+/// Everything `Lobby::send_players` sends
+const LOBBY_PLAYERS: &[&str] = &["lobbyClients", "playersHost", "playersReady", "playersLostConnection"];
+/// Everything a player is sent to set up the game screen
+const GAME_STATE: &[&str] = &[
+    "gamePlayers", "enabledRoles", "roleList", "modifierSettings", "playerAlive", "playerVotes", "addChatMessages",
+    "yourInsiderGroups", "yourFellowInsiders", "yourPlayerTags", "abilityState", "yourSendChatGroups", "yourPlayerIndex",
+    "yourRole", "yourRoleLabels", "yourAllowedControllers", "yourNotes", "yourCrossedOutOutlines",
+    "phase", "phaseTimeLeft", "gamePaused", "gameInitializationComplete",
+];
+
+/// Sent while the game is being created, before anyone's game state
+const STARTING_GAME: &[&str] = &[
+    "yourInsiderGroups", "startGame", "abilityState", "yourInsiderGroups", "yourInsiderGroups", "yourInsiderGroups", "yourRole",
+];
+/// Sent once every player has the game state
+const STARTED_GAME: &[&str] = &["yourPlayerTags", "yourPlayerTags", "lobbyName"];
+
+/// A lobby with a host and two other players, ready to start a seeded game of two villagers and a mafioso
+fn ready_lobby(server: &mut TestServer) -> (TestClient, TestClient, TestClient) {
+    let mut host = server.host();
+    let room_code = host.room_code();
+    let mut first = server.join(room_code);
+    let mut second = server.join(room_code);
+
+    server.send(&host, json!({"type": "setRandomSeed", "randomSeed": 0}));
+    server.send(&host, json!({"type": "setEnabledRoles", "roles": ["villager", "mafioso"]}));
+    server.send(&host, json!({
+        "type": "setRoleList",
+        "roleList": [[{"role": "villager"}], [{"role": "villager"}], [{"role": "mafioso"}]]
+    }));
+
+    host.received();
+    first.received();
+    second.received();
+    (host, first, second)
+}
+
+#[test]
+fn hosting_sends_the_lobby_to_the_host() {
+    let mut server = TestServer::new();
+    let mut host = server.host();
+
+    host.assert_received(&[
+        LOBBY_SETTINGS, &["acceptJoin"], LOBBY_PLAYERS, LOBBY_SETTINGS, &["addChatMessages", "lobbyName"]
+    ].concat());
+    host.assert_received(&[]);
+}
+
+#[test]
+fn joining_updates_everyone_in_the_lobby() {
+    let mut server = TestServer::new();
+    let mut host = server.host();
+    let room_code = host.room_code();
+    host.received();
+
+    let mut player = server.join(room_code);
+
+    player.assert_received(&[
+        LOBBY_SETTINGS, &["acceptJoin"], LOBBY_PLAYERS, LOBBY_SETTINGS, &["addChatMessages", "lobbyName"]
+    ].concat());
+    host.assert_received(&[LOBBY_SETTINGS, LOBBY_PLAYERS].concat());
+    assert_eq!(player.room_code(), room_code);
+    assert_ne!(player.player_id(), host.player_id());
+}
+
+#[test]
+fn joining_a_room_that_doesnt_exist_is_rejected() {
+    let mut server = TestServer::new();
+    let mut host = server.host();
+    let room_code = host.room_code();
+
+    let mut player = server.join(room_code.wrapping_add(1));
+
+    assert!(matches!(
+        player.received().as_slice(),
+        [ToClientPacket::RejectJoin { reason: RejectJoinReason::RoomDoesntExist }]
+    ));
+}
+
+#[test]
+fn only_the_host_can_change_settings() {
+    let mut server = TestServer::new();
+    let mut host = server.host();
+    let mut player = server.join(host.room_code());
+    host.received();
+    player.received();
+
+    server.send(&player, json!({"type": "setPhaseTime", "phase": "night", "time": 30}));
+    host.assert_received(&[]);
+    player.assert_received(&[]);
+
+    server.send(&host, json!({"type": "setPhaseTime", "phase": "night", "time": 30}));
+    host.assert_received(&["phaseTime"]);
+    player.assert_received(&["phaseTime"]);
+}
+
+#[test]
+fn starting_a_game_sends_every_player_the_game() {
+    let mut server = TestServer::new();
+    let (mut host, mut first, mut second) = ready_lobby(&mut server);
+
+    server.send(&host, json!({"type": "startGame"}));
+
+    // Which packets come before the game state depends on who got which role, which the seed decides
+    host.assert_received(&[
+        STARTING_GAME, GAME_STATE, &["playerVotes", "abilityState", "playerVotes", "abilityState"], STARTED_GAME
+    ].concat());
+    first.assert_received(&[
+        &["yourPlayerTags", "yourPlayerTags", "yourPlayerTags"], STARTING_GAME, &["playerVotes", "abilityState"], GAME_STATE,
+        &["playerVotes", "abilityState", "yourFellowInsiders", "yourRoleLabels"], STARTED_GAME
+    ].concat());
+    second.assert_received(&[
+        STARTING_GAME, &["playerVotes", "abilityState", "playerVotes", "abilityState"], GAME_STATE, STARTED_GAME
+    ].concat());
+}
+
+#[test]
+fn a_game_that_cant_be_made_isnt_started() {
+    let mut server = TestServer::new();
+    let (mut host, mut player, _) = ready_lobby(&mut server);
+
+    server.send(&host, json!({"type": "setRoleList", "roleList": [[{"role": "detective"}]]}));
+    host.received();
+    player.received();
+    server.send(&host, json!({"type": "startGame"}));
+
+    host.assert_received(&["rejectStart"]);
+    player.assert_received(&[]);
+}
+
+#[test]
+fn lobby_mutes_last_through_a_game() {
+    let mut server = TestServer::new();
+    let (mut host, mut player, _) = ready_lobby(&mut server);
+    let player_id = player.player_id();
+    server.send(&host, json!({"type": "hostSetClientMuted", "id": player_id, "muted": true}));
+    server.send(&host, json!({"type": "startGame"}));
+    server.send(&host, json!({"type": "hostForceBackToLobby"}));
+    host.received();
+    player.received();
+
+    server.send(&player, json!({"type": "sendLobbyMessage", "text": "hi"}));
+
+    host.assert_received(&[]);
+    player.assert_received(&[]);
+}
+
+#[test]
+fn phases_only_change_when_time_passes() {
+    let mut server = TestServer::new();
+    let (mut host, _, _) = ready_lobby(&mut server);
+    server.send(&host, json!({"type": "setPhaseTime", "phase": "briefing", "time": 10}));
+    server.send(&host, json!({"type": "startGame"}));
+    host.received();
+
+    server.tick(Duration::from_secs(9));
+    server.tick(Duration::from_secs(1));
+    assert!(!host.received_types().contains(&"phase".to_owned()));
+
+    // The phase ends on the first tick after its time runs out
+    server.tick(Duration::from_secs(1));
+    assert!(host.received().iter().any(|packet| matches!(packet, ToClientPacket::Phase { .. })));
+}
+
+#[test]
+fn speeding_up_the_server_speeds_up_phases() {
+    let mut server = TestServer::with_speed(10.0);
+    let (mut host, _, _) = ready_lobby(&mut server);
+    server.send(&host, json!({"type": "setPhaseTime", "phase": "briefing", "time": 10}));
+    server.send(&host, json!({"type": "startGame"}));
+    host.received();
+
+    server.tick(Duration::from_secs(1));
+    assert!(!host.received_types().contains(&"phase".to_owned()));
+
+    server.tick(Duration::from_secs(1));
+    assert!(host.received().iter().any(|packet| matches!(packet, ToClientPacket::Phase { .. })));
+}
+
+#[test]
+fn reconnecting_to_a_lobby() {
+    let mut server = TestServer::new();
+    let mut host = server.host();
+    let room_code = host.room_code();
+    let mut player = server.join(room_code);
+    let player_id = player.player_id();
+    host.received();
+
+    let mut player = server.reconnect(player);
+
+    host.assert_received(&[LOBBY_PLAYERS, LOBBY_PLAYERS].concat());
+    player.assert_received(&[
+        &["acceptJoin"], LOBBY_PLAYERS, LOBBY_SETTINGS, &["addChatMessages", "lobbyName"]
+    ].concat());
+    assert_eq!(player.player_id(), player_id);
+}
+
+#[test]
+fn reconnecting_to_a_game() {
+    let mut server = TestServer::new();
+    let (mut host, player, _) = ready_lobby(&mut server);
+    server.send(&host, json!({"type": "startGame"}));
+    server.tick(Duration::from_secs(1));
+    host.received();
+
+    let mut player = server.reconnect(player);
+
+    player.assert_received(&[&["acceptJoin"], GAME_STATE, &["playersHost", "lobbyName"]].concat());
+    host.assert_received(&["hostData", "hostData", "playerVotes", "abilityState"]);
+}
+
+#[test]
+fn reconnecting_to_a_game_sends_the_game_state_once() {
+    let mut server = TestServer::new();
+    let (host, player, _) = ready_lobby(&mut server);
+    server.send(&host, json!({"type": "startGame"}));
+    server.tick(Duration::from_secs(1));
+
+    let mut player = server.reconnect(player);
+    server.tick(Duration::from_secs(1));
+
+    let received = player.received_types();
+    for packet in GAME_STATE {
+        let times = received.iter().filter(|received| received == packet).count();
+        assert_eq!(times, 1, "{packet} was sent {times} times");
+    }
+}
+
+#[test]
+fn rejoining_as_someone_else_is_rejected() {
+    let mut server = TestServer::new();
+    let mut host = server.host();
+    let room_code = host.room_code();
+    let mut player = server.join(room_code);
+    let player_id = player.player_id();
+    host.received();
+
+    let mut impostor = server.connect();
+    server.send(&impostor, json!({"type": "reJoin", "roomCode": room_code, "playerId": player_id}));
+
+    assert!(matches!(
+        impostor.received().as_slice(),
+        [ToClientPacket::RejectJoin { reason: RejectJoinReason::PlayerTaken }]
+    ));
+    host.assert_received(&[]);
+}
+
+/// The seat, role and chat messages a player was sent in `packets`
+fn seat(packets: &[ToClientPacket]) -> (Option<PlayerIndex>, Option<Role>, Vec<ChatMessage>) {
+    let (mut player_index, mut role, mut chat) = (None, None, Vec::new());
+    for packet in packets {
+        match packet {
+            ToClientPacket::YourPlayerIndex { player_index: index } => player_index = Some(*index),
+            ToClientPacket::YourRole { role: new_role } => role = Some(*new_role),
+            ToClientPacket::AddChatMessages { chat_messages } => chat.extend(chat_messages.values().cloned()),
+            _ => {}
+        }
+    }
+    (player_index, role, chat)
+}
+
+#[test]
+fn host_replaces_a_disconnected_player_with_a_spectator() {
+    let mut server = TestServer::new();
+    let (host, mut player, _) = ready_lobby(&mut server);
+    server.send(&host, json!({"type": "startGame"}));
+    server.tick(Duration::from_secs(1));
+    let (room_code, player_id) = (player.room_code(), player.player_id());
+    let (player_index, role, chat) = seat(&player.received());
+    server.disconnect(player);
+
+    let mut spectator = server.join(room_code);
+    let spectator_id = spectator.player_id();
+    spectator.received();
+    server.send(&host, json!({"type": "hostReplacePlayer", "id": player_id, "replacementId": spectator_id}));
+
+    let (replacement_index, replacement_role, replacement_chat) = seat(&spectator.received());
+    assert!(player_index.is_some() && !chat.is_empty());
+    assert_eq!(replacement_index, player_index);
+    assert_eq!(replacement_role, role);
+    assert!(chat.iter().all(|message| replacement_chat.contains(message)));
+
+    // The old client can't take the seat back
+    let mut old_client = server.connect();
+    server.send(&old_client, json!({"type": "reJoin", "roomCode": room_code, "playerId": player_id}));
+    assert!(matches!(old_client.received().as_slice(), [ToClientPacket::RejectJoin { .. }]));
+}
+
+#[test]
+fn connected_players_cant_be_replaced() {
+    let mut server = TestServer::new();
+    let (host, mut player, _) = ready_lobby(&mut server);
+    server.send(&host, json!({"type": "startGame"}));
+    server.tick(Duration::from_secs(1));
+    let (room_code, player_id) = (player.room_code(), player.player_id());
+
+    let mut spectator = server.join(room_code);
+    let spectator_id = spectator.player_id();
+    spectator.received();
+    player.received();
+    server.send(&host, json!({"type": "hostReplacePlayer", "id": player_id, "replacementId": spectator_id}));
+
+    assert_eq!(seat(&spectator.received()).0, None);
+    assert_eq!(seat(&player.received()).0, None);
+}
+
+#[test]
+fn players_can_only_be_replaced_by_spectators() {
+    let mut server = TestServer::new();
+    let (host, mut player, mut other_player) = ready_lobby(&mut server);
+    server.send(&host, json!({"type": "startGame"}));
+    server.tick(Duration::from_secs(1));
+    let other_player_id = other_player.player_id();
+    let (room_code, player_id) = (player.room_code(), player.player_id());
+    server.disconnect(player);
+    other_player.received();
+
+    server.send(&host, json!({"type": "hostReplacePlayer", "id": player_id, "replacementId": other_player_id}));
+
+    assert_eq!(seat(&other_player.received()).0, None);
+    let mut player = server.connect();
+    server.send(&player, json!({"type": "reJoin", "roomCode": room_code, "playerId": player_id}));
+    assert_eq!(player.player_id(), player_id);
+}
+
+#[test]
+fn banning_an_address_disconnects_its_clients() {
+    let path = std::env::temp_dir().join(format!("mafia_server_ban_list_{}", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let mut server = TestServer::with_ban_list(path.clone());
+    let mut host = server.host();
+    let room_code = host.room_code();
+    let mut player = server.join(room_code);
+    host.received();
+    player.received();
+
+    std::fs::write(&path, "# Everyone in this test connects from here\n127.0.0.1\n").expect("The ban list should be writable");
+    server.tick(Duration::from_secs(1));
+    let _ = std::fs::remove_file(&path);
+
+    // Whoever is disconnected first is seen leaving by the other, if they're still there
+    assert_eq!(host.received_types().last().map(String::as_str), Some("forcedDisconnect"));
+    assert_eq!(player.received_types().last().map(String::as_str), Some("forcedDisconnect"));
+    let mut rejoining = server.join(room_code);
+    assert!(matches!(
+        rejoining.received().as_slice(),
+        [ToClientPacket::RejectJoin { reason: RejectJoinReason::Banned }]
+    ));
+}
+
+#[test]
+fn a_client_that_stops_pinging_is_disconnected() {
+    let mut server = TestServer::new();
+    let mut client = server.connect();
+
+    server.tick(Duration::from_secs(6));
+    client.assert_received(&["pong"]);
+    server.send(&client, json!({"type": "ping"}));
+
+    server.tick(Duration::from_secs(6));
+    client.assert_received(&["pong"]);
+
+    server.tick(Duration::from_secs(5));
+    client.assert_received(&["pong", "forcedDisconnect"]);
+}
+
+#[test]
+fn the_rate_limit_wears_off_as_time_passes() {
+    let mut server = TestServer::new();
+    let mut host = server.host();
+    host.received();
+
+    for _ in 0..20 {
+        server.send(&host, json!({"type": "sendLobbyMessage", "text": "hi"}));
+    }
+    host.received();
+    server.send(&host, json!({"type": "sendLobbyMessage", "text": "hi"}));
+    host.assert_received(&["rateLimitExceeded"]);
+
+    // Pinging so the host isn't disconnected while waiting
+    for _ in 0..3 {
+        server.send(&host, json!({"type": "ping"}));
+        server.tick(Duration::from_secs(7));
+    }
+    host.received();
+    server.send(&host, json!({"type": "sendLobbyMessage", "text": "hi"}));
+    host.assert_received(&["addChatMessages"]);
+}
+
+#[test]
+fn speeding_up_the_server_doesnt_speed_up_pings_or_the_rate_limit() {
+    let mut server = TestServer::with_speed(100.0);
+    let mut host = server.host();
+    host.received();
+
+    for _ in 0..21 {
+        server.send(&host, json!({"type": "sendLobbyMessage", "text": "hi"}));
+    }
+    host.received();
+
+    for _ in 0..5 {
+        server.send(&host, json!({"type": "ping"}));
+        server.tick(Duration::from_secs(1));
+    }
+    assert!(!host.received_types().contains(&"forcedDisconnect".to_owned()));
+    server.send(&host, json!({"type": "sendLobbyMessage", "text": "hi"}));
+    host.assert_received(&["rateLimitExceeded"]);
+}
+
+fn wait_for_probabilities(client: &mut TestClient) -> RoleListProbabilities {
+    client.wait_for(|packet| match packet {
+        ToClientPacket::RoleListProbabilities { probabilities } => Some(probabilities.clone()),
+        _ => None,
+    })
+}
+
+#[test]
+fn role_list_probabilities_are_sent_to_the_host_who_asked() {
+    let mut server = TestServer::new();
+    let (mut host, mut player, _) = ready_lobby(&mut server);
+
+    server.send(&host, json!({"type": "roleListProbabilitiesRequest"}));
+    let probabilities = wait_for_probabilities(&mut host);
+
+    assert_eq!(probabilities.samples, RoleListProbabilities::SAMPLES);
+    assert_eq!(probabilities.failed_samples, 0);
+    assert_eq!(probabilities.roles.get(&Role::Villager), Some(&1.0));
+    assert_eq!(probabilities.roles.get(&Role::Mafioso), Some(&1.0));
+    assert_eq!(probabilities.faction_sizes.get(&GameConclusion::Town), Some(&vec![0.0, 0.0, 1.0, 0.0]));
+    assert_eq!(probabilities.faction_sizes.get(&GameConclusion::Mafia), Some(&vec![0.0, 1.0, 0.0, 0.0]));
+    player.assert_received(&[]);
+}
+
+#[test]
+fn only_the_host_can_ask_for_role_list_probabilities() {
+    let mut server = TestServer::new();
+    let (mut host, mut player, _) = ready_lobby(&mut server);
+
+    server.send(&player, json!({"type": "roleListProbabilitiesRequest"}));
+    // If the player had started an estimate, the host's would be ignored until it finished
+    server.send(&host, json!({"type": "roleListProbabilitiesRequest"}));
+    wait_for_probabilities(&mut host);
+
+    host.assert_received(&[]);
+    player.assert_received(&[]);
+}
+
+#[test]
+fn role_list_probabilities_follow_the_outline_options() {
+    let mut server = TestServer::new();
+    let (mut host, _, _) = ready_lobby(&mut server);
+    server.send(&host, json!({"type": "setEnabledRoles", "roles": ["villager", "detective", "mafioso"]}));
+    server.send(&host, json!({
+        "type": "setRoleList",
+        "roleList": [[{"role": "villager"}, {"role": "detective"}], [{"role": "villager"}], [{"role": "mafioso"}]]
+    }));
+
+    server.send(&host, json!({"type": "roleListProbabilitiesRequest"}));
+    let probabilities = wait_for_probabilities(&mut host);
+
+    let detective = probabilities.roles.get(&Role::Detective).copied().unwrap_or_default();
+    assert!((0.4..0.6).contains(&detective), "Detective appeared in {detective} of the games");
+    assert_eq!(probabilities.roles.get(&Role::Villager), Some(&1.0));
+    assert_eq!(probabilities.faction_sizes.get(&GameConclusion::Town), Some(&vec![0.0, 0.0, 1.0, 0.0]));
+}
+
+#[test]
+fn role_list_probabilities_count_lists_that_cant_be_made() {
+    let mut server = TestServer::new();
+    let (mut host, _, _) = ready_lobby(&mut server);
+    server.send(&host, json!({"type": "setRoleList", "roleList": [[{"role": "detective"}]]}));
+
+    server.send(&host, json!({"type": "roleListProbabilitiesRequest"}));
+    let probabilities = wait_for_probabilities(&mut host);
+
+    assert_eq!(probabilities.failed_samples, probabilities.samples);
+    assert!(probabilities.roles.is_empty());
+    assert!(probabilities.faction_sizes.is_empty());
+}
@@ -0,0 +1,162 @@
+mod kit;
+
+use std::net::SocketAddr;
+
+use mafia_server::{
+    game::{
+        phase::PhaseType,
+        prelude::*,
+        settings::{Settings, SpectatorSettings},
+        Game
+    },
+    packet::{RejectJoinReason, ToClientPacket},
+    room::RoomState,
+    websocket_connections::connection::Connection
+};
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+fn join_spectator(game: &mut Game, port: u16) -> Result<UnboundedReceiver<ToClientPacket>, RejectJoinReason> {
+    let (tx, receiver) = mpsc::unbounded_channel();
+    let sender = Connection::new(tx, SocketAddr::from(([127, 0, 0, 1], port))).sender();
+
+    let result = game.join_client(&sender)?;
+    game.initialize_client(result.id, &sender);
+
+    Ok(receiver)
+}
+
+fn settings(spectators: SpectatorSettings) -> Settings {
+    Settings { spectators, ..Default::default() }
+}
+
+fn delayed_by(feed_delay: u8) -> Settings {
+    settings(SpectatorSettings { feed_delay, ..Default::default() })
+}
+
+fn received_phases(receiver: &mut UnboundedReceiver<ToClientPacket>) -> Vec<PhaseType> {
+    let mut phases = Vec::new();
+    while let Ok(packet) = receiver.try_recv() {
+        if let ToClientPacket::Phase { phase, .. } = packet {
+            phases.push(phase.phase());
+        }
+    }
+    phases
+}
+
+#[test]
+fn delayed_spectator_feed() {
+    let settings = delayed_by(1);
+    kit::scenario!(game in Briefing 1 with settings where
+        _detective: Detective,
+        _townie: Villager,
+        _goon: Goon
+    );
+
+    let Ok(mut spectator) = join_spectator(&mut game, 1) else { panic!("Failed to join as spectator") };
+    assert!(received_phases(&mut spectator).is_empty());
+
+    game.next_phase();
+    let dusk = game.current_phase().phase();
+    assert!(!received_phases(&mut spectator).contains(&dusk));
+
+    game.next_phase();
+    assert_eq!(received_phases(&mut spectator), vec![dusk]);
+
+    // Spectators who join late are caught up to the same point
+    let Ok(mut late_spectator) = join_spectator(&mut game, 2) else { panic!("Failed to join as spectator") };
+    assert_eq!(received_phases(&mut late_spectator), vec![dusk]);
+}
+
+#[test]
+fn late_spectators_dont_see_deaths_the_feed_is_holding_back() {
+    let settings = delayed_by(3);
+    kit::scenario!(game in Night 2 with settings where
+        mafioso: Mafioso,
+        townie: Villager,
+        _detective: Detective,
+        _doctor: Doctor
+    );
+    mafioso.send_ability_input_player_list_typical(townie);
+    game.skip_to(PhaseType::Obituary, 3);
+    assert!(!townie.alive());
+
+    let Ok(mut spectator) = join_spectator(&mut game, 1) else { panic!("Failed to join as spectator") };
+
+    let mut saw_alive = false;
+    while let Ok(packet) = spectator.try_recv() {
+        match packet {
+            ToClientPacket::PlayerAlive { alive } => {
+                assert!(alive.iter().all(|alive| *alive), "Spectator saw a death too early: {alive:?}");
+                saw_alive = true;
+            }
+            ToClientPacket::AddGrave { .. } => panic!("Spectator saw a grave too early"),
+            _ => {}
+        }
+    }
+    assert!(saw_alive);
+}
+
+#[test]
+fn released_spectator_feed_only_keeps_the_latest_state() {
+    let settings = delayed_by(1);
+    kit::scenario!(game in Night 2 with settings where
+        mafioso: Mafioso,
+        townie: Villager,
+        _detective: Detective,
+        _doctor: Doctor
+    );
+    mafioso.send_ability_input_player_list_typical(townie);
+    game.skip_to(PhaseType::Night, 4);
+
+    let released = game.spectator_feed.released_packets();
+    let graves = released.iter().filter(|packet| matches!(packet, ToClientPacket::AddGrave { .. })).count();
+    let phases = released.iter().filter(|packet| matches!(packet, ToClientPacket::Phase { .. })).count();
+    assert_eq!(graves, 1);
+    assert_eq!(phases, 1);
+
+    // Everything released so far is still enough to catch a new spectator up
+    let Ok(mut spectator) = join_spectator(&mut game, 1) else { panic!("Failed to join as spectator") };
+    let mut alive = None;
+    while let Ok(packet) = spectator.try_recv() {
+        if let ToClientPacket::PlayerAlive { alive: new } = packet {
+            alive = Some(new);
+        }
+    }
+    assert_eq!(alive, Some(vec![true, false, true, true]));
+}
+
+#[test]
+fn omniscient_spectators_see_roles() {
+    let settings = settings(SpectatorSettings { omniscient: true, ..Default::default() });
+    kit::scenario!(game in Briefing 1 with settings where
+        detective: Detective,
+        townie: Villager,
+        goon: Goon
+    );
+
+    let Ok(mut spectator) = join_spectator(&mut game, 1) else { panic!("Failed to join as spectator") };
+
+    let mut role_labels = None;
+    while let Ok(packet) = spectator.try_recv() {
+        if let ToClientPacket::YourRoleLabels { role_labels: labels } = packet {
+            role_labels = Some(labels);
+        }
+    }
+    let Some(role_labels) = role_labels else { panic!("Spectator didn't receive role labels") };
+
+    assert_eq!(role_labels.get(&detective.player_ref()), Some(&Role::Detective));
+    assert_eq!(role_labels.get(&townie.player_ref()), Some(&Role::Villager));
+    assert_eq!(role_labels.get(&goon.player_ref()), Some(&Role::Goon));
+}
+
+#[test]
+fn mid_game_spectators_can_be_disallowed() {
+    let settings = settings(SpectatorSettings { allow_mid_game_join: false, ..Default::default() });
+    kit::scenario!(game in Night 1 with settings where
+        _detective: Detective,
+        _townie: Villager,
+        _goon: Goon
+    );
+
+    assert!(matches!(join_spectator(&mut game, 1), Err(RejectJoinReason::GameAlreadyStarted)));
+}